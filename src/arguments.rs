@@ -1,20 +1,60 @@
+#![allow(clippy::needless_return)]
+
+use crate::configuration::{
+    ConfigCase, ConfigCommaStyle, ConfigCommentStyle, ConfigDialect, ConfigLayout,
+    ConfigLineEndings,
+};
+use crate::format::FormatRange;
+
 pub struct Arguments {
     arg_type: Option<ArgType>,
 
     pub help: bool,
     pub version: bool,
+    pub lsp: bool,
 
     pub input: Option<String>,
     pub output: Option<String>,
+    pub paths: Vec<String>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub range: Option<FormatRange>,
+    pub config: Option<String>,
+    pub no_config: bool,
+
+    pub check: bool,
+    pub diff: bool,
+    pub write: bool,
+    pub strict: bool,
+    pub print_config: bool,
 
     pub newlines: bool,
     pub comment_pre_space: bool,
     pub align_text_groups: bool,
+    pub collapse_short_statements: bool,
+    pub collapse_short_clauses: bool,
+    pub align_aliases: bool,
+    pub align_assignments: bool,
+    pub align_case_then: bool,
+    pub wrap_comments: bool,
+    pub indent_block_comments: bool,
+    pub block_comment_gutters: bool,
     pub upper: bool,
     pub lower: bool,
+    pub consistent: bool,
+    pub identifier_case: Option<ConfigCase>,
+    pub function_case: Option<ConfigCase>,
+    pub datatype_case: Option<ConfigCase>,
     pub tabs: bool,
-    pub spaces: Option<u8>,
-    pub chars: Option<u8>,
+    pub spaces: Option<u16>,
+    pub chars: Option<u16>,
+    pub comment_chars: Option<u16>,
+    pub paren_chars: Option<u16>,
+    pub comma_style: Option<ConfigCommaStyle>,
+    pub layout: Option<ConfigLayout>,
+    pub comment_style: Option<ConfigCommentStyle>,
+    pub line_endings: Option<ConfigLineEndings>,
+    pub dialect: Option<ConfigDialect>,
 }
 
 impl Arguments {
@@ -24,25 +64,57 @@ impl Arguments {
 
             help: false,
             version: false,
+            lsp: false,
 
             input: None,
             output: None,
+            paths: vec![],
+            include: vec![],
+            exclude: vec![],
+            range: None,
+            config: None,
+            no_config: false,
+
+            check: false,
+            diff: false,
+            write: false,
+            strict: false,
+            print_config: false,
 
             newlines: false,
             comment_pre_space: false,
             align_text_groups: false,
+            collapse_short_statements: false,
+            collapse_short_clauses: false,
+            align_aliases: false,
+            align_assignments: false,
+            align_case_then: false,
+            wrap_comments: false,
+            indent_block_comments: false,
+            block_comment_gutters: false,
             upper: false,
             lower: false,
+            consistent: false,
+            identifier_case: None,
+            function_case: None,
+            datatype_case: None,
             tabs: false,
             spaces: None,
             chars: None,
+            comment_chars: None,
+            paren_chars: None,
+            comma_style: None,
+            layout: None,
+            comment_style: None,
+            line_endings: None,
+            dialect: None,
         }
     }
 
     pub fn from<T: Iterator<Item = String>>(args: T) -> Result<Arguments, &'static str> {
         let mut arguments: Arguments = Arguments::new();
 
-        for arg in args {
+        for (i, arg) in args.enumerate() {
             match arg.as_str() {
                 "-h" | "--help" => {
                     arguments.help = true;
@@ -50,6 +122,9 @@ impl Arguments {
                 "-v" | "--version" => {
                     arguments.version = true;
                 }
+                "lsp" if i == 0 => {
+                    arguments.lsp = true;
+                }
                 "-i" | "--input" => {
                     if arguments.arg_type.is_some() {
                         return Err("Invalid arguments provided.");
@@ -62,6 +137,54 @@ impl Arguments {
                     }
                     arguments.arg_type = Some(ArgType::Output);
                 }
+                "--include" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Include);
+                }
+                "--exclude" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Exclude);
+                }
+                "--range" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Range);
+                }
+                "--byte-range" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::ByteRange);
+                }
+                "--config" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Config);
+                }
+                "--no-config" => {
+                    arguments.no_config = true;
+                }
+                "--check" => {
+                    arguments.check = true;
+                }
+                "--diff" => {
+                    arguments.diff = true;
+                }
+                "-w" | "--write" => {
+                    arguments.write = true;
+                }
+                "--strict" => {
+                    arguments.strict = true;
+                }
+                "--print-config" => {
+                    arguments.print_config = true;
+                }
                 "-n" | "--newlines" => {
                     arguments.newlines = true;
                 }
@@ -71,6 +194,27 @@ impl Arguments {
                 "-l" | "--lower" => {
                     arguments.lower = true;
                 }
+                "--consistent" => {
+                    arguments.consistent = true;
+                }
+                "--identifier-case" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::IdentifierCase);
+                }
+                "--function-case" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::FunctionCase);
+                }
+                "--datatype-case" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::DatatypeCase);
+                }
                 "-t" | "--tabs" => {
                     arguments.tabs = true;
                 }
@@ -86,12 +230,78 @@ impl Arguments {
                     }
                     arguments.arg_type = Some(ArgType::Chars);
                 }
+                "--comment-chars" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::CommentChars);
+                }
+                "--paren-chars" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::ParenChars);
+                }
+                "--comma-style" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::CommaStyle);
+                }
+                "--layout" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Layout);
+                }
+                "--comment-style" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::CommentStyle);
+                }
+                "--line-endings" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::LineEndings);
+                }
+                "-d" | "--dialect" => {
+                    if arguments.arg_type.is_some() {
+                        return Err("Invalid arguments provided.");
+                    }
+                    arguments.arg_type = Some(ArgType::Dialect);
+                }
                 "--comment-pre-space" => {
                     arguments.comment_pre_space = true;
                 }
                 "--align-text-groups" => {
                     arguments.align_text_groups = true;
                 }
+                "--collapse-short-statements" => {
+                    arguments.collapse_short_statements = true;
+                }
+                "--collapse-short-clauses" => {
+                    arguments.collapse_short_clauses = true;
+                }
+                "--align-aliases" => {
+                    arguments.align_aliases = true;
+                }
+                "--align-assignments" => {
+                    arguments.align_assignments = true;
+                }
+                "--align-case-then" => {
+                    arguments.align_case_then = true;
+                }
+                "--wrap-comments" => {
+                    arguments.wrap_comments = true;
+                }
+                "--indent-block-comments" => {
+                    arguments.indent_block_comments = true;
+                }
+                "--block-comment-gutters" => {
+                    arguments.block_comment_gutters = true;
+                }
                 _ => match arguments.arg_type {
                     Some(ArgType::Input) => {
                         arguments.input = Some(arg);
@@ -101,24 +311,154 @@ impl Arguments {
                         arguments.output = Some(arg);
                         arguments.arg_type = None;
                     }
+                    Some(ArgType::Include) => {
+                        arguments.include.push(arg);
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::Exclude) => {
+                        arguments.exclude.push(arg);
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::Range) => {
+                        let range: Option<FormatRange> = FormatRange::parse(&arg, true);
+                        if range.is_none() {
+                            return Err(
+                                "Invalid range provided (must be <START_LINE>:<END_LINE>, starting at 1).",
+                            );
+                        }
+                        arguments.range = range;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::ByteRange) => {
+                        let range: Option<FormatRange> = FormatRange::parse(&arg, false);
+                        if range.is_none() {
+                            return Err("Invalid byte range provided (must be <START>:<END>).");
+                        }
+                        arguments.range = range;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::Config) => {
+                        arguments.config = Some(arg);
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::IdentifierCase) => {
+                        let case: Option<ConfigCase> = ConfigCase::from_name(&arg);
+                        if case.is_none() {
+                            return Err(
+                                "Invalid identifier case provided (must be upper, lower, preserve or consistent).",
+                            );
+                        }
+                        arguments.identifier_case = case;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::FunctionCase) => {
+                        let case: Option<ConfigCase> = ConfigCase::from_name(&arg);
+                        if case.is_none() {
+                            return Err(
+                                "Invalid function case provided (must be upper, lower, preserve or consistent).",
+                            );
+                        }
+                        arguments.function_case = case;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::DatatypeCase) => {
+                        let case: Option<ConfigCase> = ConfigCase::from_name(&arg);
+                        if case.is_none() {
+                            return Err(
+                                "Invalid datatype case provided (must be upper, lower, preserve or consistent).",
+                            );
+                        }
+                        arguments.datatype_case = case;
+                        arguments.arg_type = None;
+                    }
                     Some(ArgType::Spaces) => {
-                        let spaces: Result<u8, std::num::ParseIntError> = arg.parse::<u8>();
+                        let spaces: Result<u16, std::num::ParseIntError> = arg.parse::<u16>();
                         if spaces.is_err() {
-                            return Err("Invalid space size provided (must be 0-255).");
+                            return Err("Invalid space size provided (must be 0-65535).");
                         }
                         arguments.spaces = Some(spaces.unwrap());
                         arguments.arg_type = None;
                     }
                     Some(ArgType::Chars) => {
-                        let chars: Result<u8, std::num::ParseIntError> = arg.parse::<u8>();
+                        let chars: Result<u16, std::num::ParseIntError> = arg.parse::<u16>();
                         if chars.is_err() {
-                            return Err("Invalid char size provided (must be 0-255).");
+                            return Err("Invalid char size provided (must be 0-65535).");
                         }
                         arguments.chars = Some(chars.unwrap());
                         arguments.arg_type = None;
                     }
+                    Some(ArgType::CommentChars) => {
+                        let chars: Result<u16, std::num::ParseIntError> = arg.parse::<u16>();
+                        if chars.is_err() {
+                            return Err("Invalid comment char size provided (must be 0-65535).");
+                        }
+                        arguments.comment_chars = Some(chars.unwrap());
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::ParenChars) => {
+                        let chars: Result<u16, std::num::ParseIntError> = arg.parse::<u16>();
+                        if chars.is_err() {
+                            return Err("Invalid paren char size provided (must be 0-65535).");
+                        }
+                        arguments.paren_chars = Some(chars.unwrap());
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::CommaStyle) => {
+                        let comma_style: Option<ConfigCommaStyle> =
+                            ConfigCommaStyle::from_name(&arg);
+                        if comma_style.is_none() {
+                            return Err(
+                                "Invalid comma style provided (must be leading or trailing).",
+                            );
+                        }
+                        arguments.comma_style = comma_style;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::Layout) => {
+                        let layout: Option<ConfigLayout> = ConfigLayout::from_name(&arg);
+                        if layout.is_none() {
+                            return Err("Invalid layout provided (must be standard or river).");
+                        }
+                        arguments.layout = layout;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::CommentStyle) => {
+                        let comment_style: Option<ConfigCommentStyle> =
+                            ConfigCommentStyle::from_name(&arg);
+                        if comment_style.is_none() {
+                            return Err(
+                                "Invalid comment style provided (must be preserve, line or block).",
+                            );
+                        }
+                        arguments.comment_style = comment_style;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::LineEndings) => {
+                        let line_endings: Option<ConfigLineEndings> =
+                            ConfigLineEndings::from_name(&arg);
+                        if line_endings.is_none() {
+                            return Err(
+                                "Invalid line endings provided (must be auto, lf or crlf).",
+                            );
+                        }
+                        arguments.line_endings = line_endings;
+                        arguments.arg_type = None;
+                    }
+                    Some(ArgType::Dialect) => {
+                        let dialect: Option<ConfigDialect> = ConfigDialect::from_name(&arg);
+                        if dialect.is_none() {
+                            return Err(
+                                "Invalid dialect provided (must be generic, postgres, mysql, tsql, sqlite, bigquery or snowflake).",
+                            );
+                        }
+                        arguments.dialect = dialect;
+                        arguments.arg_type = None;
+                    }
                     None => {
-                        return Err("Unknown argument provided");
+                        if arg.starts_with('-') {
+                            return Err("Unknown argument provided");
+                        }
+                        arguments.paths.push(arg);
                     }
                 },
             }
@@ -128,6 +468,10 @@ impl Arguments {
             return Err("Invalid arguments provided");
         }
 
+        if arguments.config.is_some() && arguments.no_config {
+            return Err("Config file and no config can not be used together.");
+        }
+
         return Ok(arguments);
     }
 }
@@ -135,11 +479,27 @@ impl Arguments {
 enum ArgType {
     Input,
     Output,
+    Include,
+    Exclude,
+    Range,
+    ByteRange,
+    Config,
+    IdentifierCase,
+    FunctionCase,
+    DatatypeCase,
     Spaces,
     Chars,
+    CommentChars,
+    ParenChars,
+    CommaStyle,
+    Layout,
+    CommentStyle,
+    LineEndings,
+    Dialect,
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -151,16 +511,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -172,32 +563,106 @@ mod tests {
             String::from("in.sql"),
             String::from("-o"),
             String::from("out.sql"),
+            String::from("--include"),
+            String::from("*.sql"),
+            String::from("--exclude"),
+            String::from("old"),
+            String::from("dir"),
+            String::from("--range"),
+            String::from("2:4"),
+            String::from("--config"),
+            String::from("sqlfmt.toml"),
+            String::from("--check"),
+            String::from("--diff"),
+            String::from("-w"),
+            String::from("--strict"),
+            String::from("--print-config"),
             String::from("-n"),
             String::from("--comment-pre-space"),
             String::from("--align-text-groups"),
+            String::from("--collapse-short-statements"),
+            String::from("--collapse-short-clauses"),
+            String::from("--align-aliases"),
+            String::from("--align-assignments"),
+            String::from("--align-case-then"),
+            String::from("--wrap-comments"),
+            String::from("--indent-block-comments"),
+            String::from("--block-comment-gutters"),
             String::from("-u"),
             String::from("-l"),
+            String::from("--consistent"),
+            String::from("--identifier-case"),
+            String::from("lower"),
+            String::from("--function-case"),
+            String::from("upper"),
+            String::from("--datatype-case"),
+            String::from("preserve"),
             String::from("-t"),
             String::from("-s"),
             String::from("2"),
             String::from("-c"),
             String::from("120"),
+            String::from("--comment-chars"),
+            String::from("100"),
+            String::from("--paren-chars"),
+            String::from("60"),
+            String::from("--comma-style"),
+            String::from("leading"),
+            String::from("--layout"),
+            String::from("river"),
+            String::from("--comment-style"),
+            String::from("line"),
+            String::from("--line-endings"),
+            String::from("crlf"),
+            String::from("-d"),
+            String::from("postgres"),
         ];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, true);
         assert_eq!(arguments.version, true);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, Some(String::from("in.sql")));
         assert_eq!(arguments.output, Some(String::from("out.sql")));
+        assert_eq!(arguments.paths, vec![String::from("dir")]);
+        assert_eq!(arguments.include, vec![String::from("*.sql")]);
+        assert_eq!(arguments.exclude, vec![String::from("old")]);
+        assert_eq!(arguments.range, Some(FormatRange::Lines(2, 4)));
+        assert_eq!(arguments.config, Some(String::from("sqlfmt.toml")));
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, true);
+        assert_eq!(arguments.diff, true);
+        assert_eq!(arguments.write, true);
+        assert_eq!(arguments.strict, true);
+        assert_eq!(arguments.print_config, true);
         assert_eq!(arguments.newlines, true);
         assert_eq!(arguments.comment_pre_space, true);
         assert_eq!(arguments.align_text_groups, true);
+        assert_eq!(arguments.collapse_short_statements, true);
+        assert_eq!(arguments.collapse_short_clauses, true);
+        assert_eq!(arguments.align_aliases, true);
+        assert_eq!(arguments.align_assignments, true);
+        assert_eq!(arguments.align_case_then, true);
+        assert_eq!(arguments.wrap_comments, true);
+        assert_eq!(arguments.indent_block_comments, true);
+        assert_eq!(arguments.block_comment_gutters, true);
         assert_eq!(arguments.upper, true);
         assert_eq!(arguments.lower, true);
+        assert_eq!(arguments.consistent, true);
+        assert_eq!(arguments.identifier_case, Some(ConfigCase::Lowercase));
+        assert_eq!(arguments.function_case, Some(ConfigCase::Uppercase));
+        assert_eq!(arguments.datatype_case, Some(ConfigCase::Unchanged));
         assert_eq!(arguments.tabs, true);
         assert_eq!(arguments.spaces, Some(2));
         assert_eq!(arguments.chars, Some(120));
+        assert_eq!(arguments.comment_chars, Some(100));
+        assert_eq!(arguments.paren_chars, Some(60));
+        assert_eq!(arguments.comma_style, Some(ConfigCommaStyle::Leading));
+        assert_eq!(arguments.layout, Some(ConfigLayout::River));
+        assert_eq!(arguments.comment_style, Some(ConfigCommentStyle::Line));
+        assert_eq!(arguments.line_endings, Some(ConfigLineEndings::Crlf));
+        assert_eq!(arguments.dialect, Some(ConfigDialect::Postgres));
     }
 
     #[test]
@@ -208,16 +673,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, true);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -228,16 +724,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, true);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -248,16 +775,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, true);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -268,16 +826,149 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, true);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_lsp() {
+        let args: Vec<String> = vec![String::from("lsp"), String::from("-u")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, true);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_lsp_not_first() {
+        let args: Vec<String> = vec![String::from("-u"), String::from("lsp")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, vec![String::from("lsp")]);
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -288,16 +979,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, Some(String::from("file.sql")));
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -308,16 +1030,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, Some(String::from("file.sql")));
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -328,16 +1081,47 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, Some(String::from("file.sql")));
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
@@ -348,308 +1132,2263 @@ mod tests {
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, Some(String::from("file.sql")));
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_newlines_short() {
-        let args: Vec<String> = vec![String::from("-n")];
+    fn test_get_arguments_paths() {
+        let args: Vec<String> = vec![String::from("a.sql"), String::from("dir")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
-        assert_eq!(arguments.newlines, true);
+        assert_eq!(
+            arguments.paths,
+            vec![String::from("a.sql"), String::from("dir")]
+        );
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_newlines_long() {
-        let args: Vec<String> = vec![String::from("--newlines")];
+    fn test_get_arguments_include() {
+        let args: Vec<String> = vec![
+            String::from("--include"),
+            String::from("*.sql"),
+            String::from("--include"),
+            String::from("migrations/**"),
+        ];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
-        assert_eq!(arguments.newlines, true);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(
+            arguments.include,
+            vec![String::from("*.sql"), String::from("migrations/**")]
+        );
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_comment_pre_space() {
-        let args: Vec<String> = vec![String::from("--comment-pre-space")];
+    fn test_get_arguments_exclude() {
+        let args: Vec<String> = vec![String::from("--exclude"), String::from("old")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, vec![String::from("old")]);
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
-        assert_eq!(arguments.comment_pre_space, true);
+        assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_align_text_groups() {
-        let args: Vec<String> = vec![String::from("--align-text-groups")];
+    fn test_get_arguments_check() {
+        let args: Vec<String> = vec![String::from("--check")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, true);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
-        assert_eq!(arguments.align_text_groups, true);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_upper_short() {
-        let args: Vec<String> = vec![String::from("-u")];
+    fn test_get_arguments_diff() {
+        let args: Vec<String> = vec![String::from("--diff")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, true);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
-        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_upper_long() {
-        let args: Vec<String> = vec![String::from("--upper")];
+    fn test_get_arguments_write_short() {
+        let args: Vec<String> = vec![String::from("-w")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, true);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
-        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_lower_short() {
-        let args: Vec<String> = vec![String::from("-l")];
+    fn test_get_arguments_write_long() {
+        let args: Vec<String> = vec![String::from("--write")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, true);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
-        assert_eq!(arguments.lower, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_lower_long() {
-        let args: Vec<String> = vec![String::from("--lower")];
+    fn test_get_arguments_strict() {
+        let args: Vec<String> = vec![String::from("--strict")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, true);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
-        assert_eq!(arguments.lower, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_tabs_short() {
-        let args: Vec<String> = vec![String::from("-t")];
+    fn test_get_arguments_print_config() {
+        let args: Vec<String> = vec![String::from("--print-config")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, true);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
-        assert_eq!(arguments.tabs, true);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_tabs_long() {
-        let args: Vec<String> = vec![String::from("--tabs")];
+    fn test_get_arguments_newlines_short() {
+        let args: Vec<String> = vec![String::from("-n")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
-        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, true);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
-        assert_eq!(arguments.tabs, true);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_spaces_short() {
-        let args: Vec<String> = vec![String::from("-s"), String::from("2")];
+    fn test_get_arguments_newlines_long() {
+        let args: Vec<String> = vec![String::from("--newlines")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
-        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, true);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
-        assert_eq!(arguments.spaces, Some(2));
+        assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_spaces_long() {
-        let args: Vec<String> = vec![String::from("--spaces"), String::from("2")];
+    fn test_get_arguments_comment_pre_space() {
+        let args: Vec<String> = vec![String::from("--comment-pre-space")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
-        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.comment_pre_space, true);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
-        assert_eq!(arguments.spaces, Some(2));
+        assert_eq!(arguments.spaces, None);
         assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_chars_short() {
-        let args: Vec<String> = vec![String::from("-c"), String::from("120")];
+    fn test_get_arguments_align_text_groups() {
+        let args: Vec<String> = vec![String::from("--align-text-groups")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
-        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.align_text_groups, true);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
-        assert_eq!(arguments.chars, Some(120));
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_chars_long() {
-        let args: Vec<String> = vec![String::from("--chars"), String::from("120")];
+    fn test_get_arguments_collapse_short_statements() {
+        let args: Vec<String> = vec![String::from("--collapse-short-statements")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
         assert_eq!(arguments.help, false);
         assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
         assert_eq!(arguments.input, None);
         assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
         assert_eq!(arguments.newlines, false);
         assert_eq!(arguments.comment_pre_space, false);
         assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, true);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
         assert_eq!(arguments.upper, false);
         assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
         assert_eq!(arguments.tabs, false);
         assert_eq!(arguments.spaces, None);
-        assert_eq!(arguments.chars, Some(120));
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_input_short_no_file() {
-        let args: Vec<String> = vec![String::from("-i")];
+    fn test_get_arguments_collapse_short_clauses() {
+        let args: Vec<String> = vec![String::from("--collapse-short-clauses")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
-        assert_eq!(arguments.is_err(), true);
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, true);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
     }
 
     #[test]
-    fn test_get_arguments_input_long_no_file() {
-        let args: Vec<String> = vec![String::from("--input")];
+    fn test_get_arguments_align_aliases() {
+        let args: Vec<String> = vec![String::from("--align-aliases")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, true);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_align_assignments() {
+        let args: Vec<String> = vec![String::from("--align-assignments")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, true);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_align_case_then() {
+        let args: Vec<String> = vec![String::from("--align-case-then")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, true);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_wrap_comments() {
+        let args: Vec<String> = vec![String::from("--wrap-comments")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, true);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_indent_block_comments() {
+        let args: Vec<String> = vec![String::from("--indent-block-comments")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, true);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_block_comment_gutters() {
+        let args: Vec<String> = vec![String::from("--block-comment-gutters")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, true);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_upper_short() {
+        let args: Vec<String> = vec![String::from("-u")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_upper_long() {
+        let args: Vec<String> = vec![String::from("--upper")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, true);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_lower_short() {
+        let args: Vec<String> = vec![String::from("-l")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, true);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_lower_long() {
+        let args: Vec<String> = vec![String::from("--lower")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, true);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_consistent() {
+        let args: Vec<String> = vec![String::from("--consistent")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, true);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_identifier_case() {
+        let args: Vec<String> = vec![String::from("--identifier-case"), String::from("upper")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, Some(ConfigCase::Uppercase));
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_function_case() {
+        let args: Vec<String> = vec![String::from("--function-case"), String::from("lower")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, Some(ConfigCase::Lowercase));
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_datatype_case() {
+        let args: Vec<String> = vec![String::from("--datatype-case"), String::from("Preserve")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, Some(ConfigCase::Unchanged));
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_tabs_short() {
+        let args: Vec<String> = vec![String::from("-t")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, true);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_tabs_long() {
+        let args: Vec<String> = vec![String::from("--tabs")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, true);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_spaces_short() {
+        let args: Vec<String> = vec![String::from("-s"), String::from("2")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, Some(2));
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_spaces_long() {
+        let args: Vec<String> = vec![String::from("--spaces"), String::from("2")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, Some(2));
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_chars_short() {
+        let args: Vec<String> = vec![String::from("-c"), String::from("120")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, Some(120));
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_chars_long() {
+        let args: Vec<String> = vec![String::from("--chars"), String::from("120")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, Some(120));
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_comment_chars() {
+        let args: Vec<String> = vec![String::from("--comment-chars"), String::from("300")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, Some(300));
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_paren_chars() {
+        let args: Vec<String> = vec![String::from("--paren-chars"), String::from("40")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, Some(40));
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_dialect_short() {
+        let args: Vec<String> = vec![String::from("-d"), String::from("tsql")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, Some(ConfigDialect::Tsql));
+    }
+
+    #[test]
+    fn test_get_arguments_dialect_long() {
+        let args: Vec<String> = vec![String::from("--dialect"), String::from("MySQL")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, Some(ConfigDialect::Mysql));
+    }
+
+    #[test]
+    fn test_get_arguments_comma_style() {
+        let args: Vec<String> = vec![String::from("--comma-style"), String::from("Leading")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, Some(ConfigCommaStyle::Leading));
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_layout() {
+        let args: Vec<String> = vec![String::from("--layout"), String::from("river")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, Some(ConfigLayout::River));
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_comment_style() {
+        let args: Vec<String> = vec![String::from("--comment-style"), String::from("Block")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, Some(ConfigCommentStyle::Block));
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_line_endings() {
+        let args: Vec<String> = vec![String::from("--line-endings"), String::from("crlf")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, Some(ConfigLineEndings::Crlf));
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_input_short_no_file() {
+        let args: Vec<String> = vec![String::from("-i")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_input_long_no_file() {
+        let args: Vec<String> = vec![String::from("--input")];
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_err(), true);
     }
@@ -695,4 +3434,324 @@ mod tests {
         let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
         assert_eq!(arguments.is_err(), true);
     }
+
+    #[test]
+    fn test_get_arguments_chars_too_large() {
+        let args: Vec<String> = vec![String::from("--chars"), String::from("300")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.ok().and_then(|a| a.chars), Some(300));
+
+        for arg in ["--chars", "--spaces", "--comment-chars", "--paren-chars"] {
+            let args: Vec<String> = vec![String::from(arg), String::from("65536")];
+            let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+            assert_eq!(arguments.is_err(), true);
+        }
+    }
+
+    #[test]
+    fn test_get_arguments_include_no_glob() {
+        let args: Vec<String> = vec![String::from("--include")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_exclude_no_glob() {
+        let args: Vec<String> = vec![String::from("--exclude")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_range() {
+        let args: Vec<String> = vec![String::from("--range"), String::from("3:7")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, Some(FormatRange::Lines(3, 7)));
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_byte_range() {
+        let args: Vec<String> = vec![String::from("--byte-range"), String::from("0:12")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, Some(FormatRange::Bytes(0, 12)));
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_config() {
+        let args: Vec<String> = vec![String::from("--config"), String::from("sqlfmt.toml")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, Some(String::from("sqlfmt.toml")));
+        assert_eq!(arguments.no_config, false);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_no_config() {
+        let args: Vec<String> = vec![String::from("--no-config")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+        assert_eq!(arguments.help, false);
+        assert_eq!(arguments.version, false);
+        assert_eq!(arguments.lsp, false);
+        assert_eq!(arguments.input, None);
+        assert_eq!(arguments.output, None);
+        assert_eq!(arguments.paths, Vec::<String>::new());
+        assert_eq!(arguments.include, Vec::<String>::new());
+        assert_eq!(arguments.exclude, Vec::<String>::new());
+        assert_eq!(arguments.range, None);
+        assert_eq!(arguments.config, None);
+        assert_eq!(arguments.no_config, true);
+        assert_eq!(arguments.check, false);
+        assert_eq!(arguments.diff, false);
+        assert_eq!(arguments.write, false);
+        assert_eq!(arguments.strict, false);
+        assert_eq!(arguments.print_config, false);
+        assert_eq!(arguments.newlines, false);
+        assert_eq!(arguments.comment_pre_space, false);
+        assert_eq!(arguments.align_text_groups, false);
+        assert_eq!(arguments.collapse_short_statements, false);
+        assert_eq!(arguments.collapse_short_clauses, false);
+        assert_eq!(arguments.align_aliases, false);
+        assert_eq!(arguments.align_assignments, false);
+        assert_eq!(arguments.align_case_then, false);
+        assert_eq!(arguments.wrap_comments, false);
+        assert_eq!(arguments.indent_block_comments, false);
+        assert_eq!(arguments.block_comment_gutters, false);
+        assert_eq!(arguments.upper, false);
+        assert_eq!(arguments.lower, false);
+        assert_eq!(arguments.consistent, false);
+        assert_eq!(arguments.identifier_case, None);
+        assert_eq!(arguments.function_case, None);
+        assert_eq!(arguments.datatype_case, None);
+        assert_eq!(arguments.tabs, false);
+        assert_eq!(arguments.spaces, None);
+        assert_eq!(arguments.chars, None);
+        assert_eq!(arguments.comment_chars, None);
+        assert_eq!(arguments.paren_chars, None);
+        assert_eq!(arguments.comma_style, None);
+        assert_eq!(arguments.layout, None);
+        assert_eq!(arguments.comment_style, None);
+        assert_eq!(arguments.line_endings, None);
+        assert_eq!(arguments.dialect, None);
+    }
+
+    #[test]
+    fn test_get_arguments_range_not_range() {
+        for range in ["3", "a:b", "7:3", "0:3", "-1:3"] {
+            let args: Vec<String> = vec![String::from("--range"), String::from(range)];
+            let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+            assert_eq!(arguments.is_err(), true);
+        }
+
+        let args: Vec<String> = vec![String::from("--byte-range"), String::from("9:8")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_unknown() {
+        let args: Vec<String> = vec![String::from("--unknown")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_dialect_no_name() {
+        let args: Vec<String> = vec![String::from("--dialect")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_dialect_not_name() {
+        let args: Vec<String> = vec![String::from("--dialect"), String::from("oracle")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_config_no_file() {
+        let args: Vec<String> = vec![String::from("--config")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_config_and_no_config() {
+        let args: Vec<String> = vec![
+            String::from("--config"),
+            String::from("sqlfmt.toml"),
+            String::from("--no-config"),
+        ];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_comma_style_not_name() {
+        let args: Vec<String> = vec![String::from("--comma-style"), String::from("both")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_layout_not_name() {
+        let args: Vec<String> = vec![String::from("--layout"), String::from("mountain")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_comment_style_not_name() {
+        let args: Vec<String> = vec![String::from("--comment-style"), String::from("hash")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_line_endings_not_name() {
+        let args: Vec<String> = vec![String::from("--line-endings"), String::from("cr")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_arguments_case_not_name() {
+        for arg in ["--identifier-case", "--function-case", "--datatype-case"] {
+            let args: Vec<String> = vec![String::from(arg), String::from("title")];
+            let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+            assert_eq!(arguments.is_err(), true);
+        }
+    }
 }
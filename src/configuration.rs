@@ -1,20 +1,41 @@
+#![allow(clippy::needless_return)]
+
 use std::{
-    env,
-    fs::{self, DirEntry, ReadDir},
+    env, fmt, fs,
     path::{Path, PathBuf},
 };
 
 use crate::arguments::Arguments;
+use crate::files;
 
-const CONFIG_FILE_NAME: &str = ".sqlfmt";
+const CONFIG_FILE_NAMES: [&str; 2] = ["sqlfmt.toml", ".sqlfmt"];
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Configuration {
     pub newlines: bool,
     pub comment_pre_space: bool,
     pub align_text_groups: bool,
+    pub collapse_short_statements: bool,
+    pub collapse_short_clauses: bool,
+    pub align_aliases: bool,
+    pub align_assignments: bool,
+    pub align_case_then: bool,
+    pub wrap_comments: bool,
+    pub indent_block_comments: bool,
+    pub block_comment_gutters: bool,
     pub case: ConfigCase,
+    pub identifier_case: ConfigCase,
+    pub function_case: Option<ConfigCase>,
+    pub datatype_case: Option<ConfigCase>,
     pub tabs: ConfigTab,
-    pub chars: u8,
+    pub chars: u16,
+    pub comment_chars: Option<u16>,
+    pub paren_chars: Option<u16>,
+    pub comma_style: ConfigCommaStyle,
+    pub layout: ConfigLayout,
+    pub comment_style: ConfigCommentStyle,
+    pub line_endings: ConfigLineEndings,
+    pub dialect: ConfigDialect,
 }
 
 impl Configuration {
@@ -23,20 +44,76 @@ impl Configuration {
             newlines: false,
             comment_pre_space: false,
             align_text_groups: false,
+            collapse_short_statements: false,
+            collapse_short_clauses: false,
+            align_aliases: false,
+            align_assignments: false,
+            align_case_then: false,
+            wrap_comments: false,
+            indent_block_comments: false,
+            block_comment_gutters: false,
             case: ConfigCase::Unchanged,
+            identifier_case: ConfigCase::Unchanged,
+            function_case: None,
+            datatype_case: None,
             tabs: ConfigTab::Space(4),
             chars: 80,
+            comment_chars: None,
+            paren_chars: None,
+            comma_style: ConfigCommaStyle::Trailing,
+            layout: ConfigLayout::Standard,
+            comment_style: ConfigCommentStyle::Preserve,
+            line_endings: ConfigLineEndings::Auto,
+            dialect: ConfigDialect::Generic,
         }
     }
 
-    pub fn from(args: &Arguments) -> Configuration {
-        let mut config: Configuration = Configuration::new();
+    /// Case of functions, defaults to keyword `case`.
+    pub fn get_function_case(&self) -> &ConfigCase {
+        return self.function_case.as_ref().unwrap_or(&self.case);
+    }
 
-        if let Ok(cwd) = env::current_dir() {
-            if let Some(file_config) = find_config(cwd.as_path()) {
-                config = file_config;
-            }
+    /// Case of data types, defaults to keyword `case`.
+    pub fn get_datatype_case(&self) -> &ConfigCase {
+        return self.datatype_case.as_ref().unwrap_or(&self.case);
+    }
+
+    /// Max chars per line with a comment, defaults to `chars`.
+    pub fn get_comment_chars(&self) -> u16 {
+        return self.comment_chars.unwrap_or(self.chars);
+    }
+
+    /// Max chars of a parenthesized expression kept inline, defaults to `chars`.
+    pub fn get_paren_chars(&self) -> u16 {
+        return self.paren_chars.unwrap_or(self.chars);
+    }
+
+    pub fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder {
+            config: Configuration::new(),
         }
+    }
+
+    pub fn from(args: &Arguments) -> Result<Configuration, ConfigError> {
+        return Configuration::from_path(args, None);
+    }
+
+    /// Get configuration for an input path, see [`get_config_file`].
+    pub fn from_path(args: &Arguments, input: Option<&Path>) -> Result<Configuration, ConfigError> {
+        let config_file: Option<ConfigFile> = get_config_file(args, input)?;
+        return Configuration::from_config_file(args, config_file.as_ref(), input);
+    }
+
+    /// Get configuration for an input path from config file values, overridden by arguments.
+    pub fn from_config_file(
+        args: &Arguments,
+        config_file: Option<&ConfigFile>,
+        input: Option<&Path>,
+    ) -> Result<Configuration, ConfigError> {
+        let mut config: Configuration = match config_file {
+            Some(config_file) => config_file.get_config(input)?,
+            None => Configuration::new(),
+        };
 
         if args.newlines {
             config.newlines = true;
@@ -50,110 +127,1104 @@ impl Configuration {
             config.align_text_groups = true;
         }
 
+        if args.collapse_short_statements {
+            config.collapse_short_statements = true;
+        }
+
+        if args.collapse_short_clauses {
+            config.collapse_short_clauses = true;
+        }
+
+        if args.align_aliases {
+            config.align_aliases = true;
+        }
+
+        if args.align_assignments {
+            config.align_assignments = true;
+        }
+
+        if args.align_case_then {
+            config.align_case_then = true;
+        }
+
+        if args.wrap_comments {
+            config.wrap_comments = true;
+        }
+
+        if args.indent_block_comments {
+            config.indent_block_comments = true;
+        }
+
+        if args.block_comment_gutters {
+            config.block_comment_gutters = true;
+        }
+
         if args.upper {
             config.case = ConfigCase::Uppercase;
         } else if args.lower {
             config.case = ConfigCase::Lowercase;
+        } else if args.consistent {
+            config.case = ConfigCase::Consistent;
+        }
+
+        if let Some(identifier_case) = &args.identifier_case {
+            config.identifier_case = identifier_case.clone();
+        }
+
+        if let Some(function_case) = &args.function_case {
+            config.function_case = Some(function_case.clone());
+        }
+
+        if let Some(datatype_case) = &args.datatype_case {
+            config.datatype_case = Some(datatype_case.clone());
         }
 
         if args.tabs {
             config.tabs = ConfigTab::Tab;
         }
 
-        if args.spaces.is_some() {
-            config.tabs = ConfigTab::Space(args.spaces.unwrap());
+        if let Some(spaces) = args.spaces {
+            config.tabs = ConfigTab::Space(spaces);
+        }
+
+        if let Some(chars) = args.chars {
+            config.chars = chars;
         }
 
-        if args.chars.is_some() {
-            config.chars = args.chars.unwrap();
+        if let Some(comment_chars) = args.comment_chars {
+            config.comment_chars = Some(comment_chars);
         }
 
-        return config;
+        if let Some(paren_chars) = args.paren_chars {
+            config.paren_chars = Some(paren_chars);
+        }
+
+        if let Some(comma_style) = &args.comma_style {
+            config.comma_style = comma_style.clone();
+        }
+
+        if let Some(layout) = &args.layout {
+            config.layout = layout.clone();
+        }
+
+        if let Some(comment_style) = &args.comment_style {
+            config.comment_style = comment_style.clone();
+        }
+
+        if let Some(line_endings) = &args.line_endings {
+            config.line_endings = line_endings.clone();
+        }
+
+        if let Some(dialect) = &args.dialect {
+            config.dialect = dialect.clone();
+        }
+
+        return Ok(config);
     }
 }
 
-fn find_config(path: &Path) -> Option<Configuration> {
+/// Formats configuration as config file content.
+impl fmt::Display for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "newlines = {}", self.newlines)?;
+        writeln!(f, "comment_pre_space = {}", self.comment_pre_space)?;
+        writeln!(f, "align_text_groups = {}", self.align_text_groups)?;
+        writeln!(
+            f,
+            "collapse_short_statements = {}",
+            self.collapse_short_statements
+        )?;
+        writeln!(
+            f,
+            "collapse_short_clauses = {}",
+            self.collapse_short_clauses
+        )?;
+        writeln!(f, "align_aliases = {}", self.align_aliases)?;
+        writeln!(f, "align_assignments = {}", self.align_assignments)?;
+        writeln!(f, "align_case_then = {}", self.align_case_then)?;
+        writeln!(f, "wrap_comments = {}", self.wrap_comments)?;
+        writeln!(f, "indent_block_comments = {}", self.indent_block_comments)?;
+        writeln!(f, "block_comment_gutters = {}", self.block_comment_gutters)?;
+        writeln!(f, "upper = {}", self.case == ConfigCase::Uppercase)?;
+        writeln!(f, "lower = {}", self.case == ConfigCase::Lowercase)?;
+        writeln!(f, "consistent = {}", self.case == ConfigCase::Consistent)?;
+        writeln!(f, "identifier_case = \"{}\"", self.identifier_case.name())?;
+        if let Some(function_case) = &self.function_case {
+            writeln!(f, "function_case = \"{}\"", function_case.name())?;
+        }
+        if let Some(datatype_case) = &self.datatype_case {
+            writeln!(f, "datatype_case = \"{}\"", datatype_case.name())?;
+        }
+        match self.tabs {
+            ConfigTab::Tab => writeln!(f, "tabs = true")?,
+            ConfigTab::Space(spaces) => writeln!(f, "spaces = {spaces}")?,
+        }
+        writeln!(f, "chars = {}", self.chars)?;
+        if let Some(comment_chars) = self.comment_chars {
+            writeln!(f, "comment_chars = {comment_chars}")?;
+        }
+        if let Some(paren_chars) = self.paren_chars {
+            writeln!(f, "paren_chars = {paren_chars}")?;
+        }
+        writeln!(f, "comma_style = \"{}\"", self.comma_style.name())?;
+        writeln!(f, "layout = \"{}\"", self.layout.name())?;
+        writeln!(f, "comment_style = \"{}\"", self.comment_style.name())?;
+        writeln!(f, "line_endings = \"{}\"", self.line_endings.name())?;
+        write!(f, "dialect = \"{}\"", self.dialect.name())
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Configuration {
+        Configuration::new()
+    }
+}
+
+pub struct ConfigurationBuilder {
+    config: Configuration,
+}
+
+impl ConfigurationBuilder {
+    pub fn newlines(mut self, newlines: bool) -> ConfigurationBuilder {
+        self.config.newlines = newlines;
+        self
+    }
+
+    pub fn comment_pre_space(mut self, comment_pre_space: bool) -> ConfigurationBuilder {
+        self.config.comment_pre_space = comment_pre_space;
+        self
+    }
+
+    pub fn align_text_groups(mut self, align_text_groups: bool) -> ConfigurationBuilder {
+        self.config.align_text_groups = align_text_groups;
+        self
+    }
+
+    pub fn collapse_short_statements(
+        mut self,
+        collapse_short_statements: bool,
+    ) -> ConfigurationBuilder {
+        self.config.collapse_short_statements = collapse_short_statements;
+        self
+    }
+
+    pub fn collapse_short_clauses(mut self, collapse_short_clauses: bool) -> ConfigurationBuilder {
+        self.config.collapse_short_clauses = collapse_short_clauses;
+        self
+    }
+
+    pub fn align_aliases(mut self, align_aliases: bool) -> ConfigurationBuilder {
+        self.config.align_aliases = align_aliases;
+        self
+    }
+
+    pub fn align_assignments(mut self, align_assignments: bool) -> ConfigurationBuilder {
+        self.config.align_assignments = align_assignments;
+        self
+    }
+
+    pub fn align_case_then(mut self, align_case_then: bool) -> ConfigurationBuilder {
+        self.config.align_case_then = align_case_then;
+        self
+    }
+
+    pub fn wrap_comments(mut self, wrap_comments: bool) -> ConfigurationBuilder {
+        self.config.wrap_comments = wrap_comments;
+        self
+    }
+
+    pub fn indent_block_comments(mut self, indent_block_comments: bool) -> ConfigurationBuilder {
+        self.config.indent_block_comments = indent_block_comments;
+        self
+    }
+
+    pub fn block_comment_gutters(mut self, block_comment_gutters: bool) -> ConfigurationBuilder {
+        self.config.block_comment_gutters = block_comment_gutters;
+        self
+    }
+
+    pub fn case(mut self, case: ConfigCase) -> ConfigurationBuilder {
+        self.config.case = case;
+        self
+    }
+
+    pub fn identifier_case(mut self, identifier_case: ConfigCase) -> ConfigurationBuilder {
+        self.config.identifier_case = identifier_case;
+        self
+    }
+
+    pub fn function_case(mut self, function_case: ConfigCase) -> ConfigurationBuilder {
+        self.config.function_case = Some(function_case);
+        self
+    }
+
+    pub fn datatype_case(mut self, datatype_case: ConfigCase) -> ConfigurationBuilder {
+        self.config.datatype_case = Some(datatype_case);
+        self
+    }
+
+    pub fn tabs(mut self, tabs: ConfigTab) -> ConfigurationBuilder {
+        self.config.tabs = tabs;
+        self
+    }
+
+    pub fn chars(mut self, chars: u16) -> ConfigurationBuilder {
+        self.config.chars = chars;
+        self
+    }
+
+    pub fn comment_chars(mut self, comment_chars: u16) -> ConfigurationBuilder {
+        self.config.comment_chars = Some(comment_chars);
+        self
+    }
+
+    pub fn paren_chars(mut self, paren_chars: u16) -> ConfigurationBuilder {
+        self.config.paren_chars = Some(paren_chars);
+        self
+    }
+
+    pub fn comma_style(mut self, comma_style: ConfigCommaStyle) -> ConfigurationBuilder {
+        self.config.comma_style = comma_style;
+        self
+    }
+
+    pub fn layout(mut self, layout: ConfigLayout) -> ConfigurationBuilder {
+        self.config.layout = layout;
+        self
+    }
+
+    pub fn comment_style(mut self, comment_style: ConfigCommentStyle) -> ConfigurationBuilder {
+        self.config.comment_style = comment_style;
+        self
+    }
+
+    pub fn line_endings(mut self, line_endings: ConfigLineEndings) -> ConfigurationBuilder {
+        self.config.line_endings = line_endings;
+        self
+    }
+
+    pub fn dialect(mut self, dialect: ConfigDialect) -> ConfigurationBuilder {
+        self.config.dialect = dialect;
+        self
+    }
+
+    pub fn build(self) -> Configuration {
+        self.config
+    }
+}
+
+/// Get the config file for an input path, `None` if not found or disabled.
+///
+/// Uses the `--config` file if provided, otherwise looks up from the input directory
+/// (or current working directory for standard input).
+pub fn get_config_file(
+    args: &Arguments,
+    input: Option<&Path>,
+) -> Result<Option<ConfigFile>, ConfigError> {
+    if args.no_config {
+        return Ok(None);
+    }
+
+    if let Some(config) = &args.config {
+        return ConfigFile::from_path(PathBuf::from(config)).map(Some);
+    }
+
+    let dir: Option<PathBuf> = match input {
+        Some(input) => get_absolute_path(input).parent().map(|p| p.to_path_buf()),
+        None => env::current_dir().ok(),
+    };
+    return match dir {
+        Some(dir) => find_config(&dir).transpose(),
+        None => Ok(None),
+    };
+}
+
+/// Find config file in the directory or up (until root).
+fn find_config(path: &Path) -> Option<Result<ConfigFile, ConfigError>> {
     if !path.is_dir() {
         return None;
     }
 
-    let entries: Result<ReadDir, std::io::Error> = path.read_dir();
-    if entries.is_err() {
-        return None;
+    for name in CONFIG_FILE_NAMES {
+        let file: PathBuf = path.join(name);
+        if file.is_file() {
+            return Some(ConfigFile::from_path(file));
+        }
     }
 
-    for entry in entries.unwrap() {
-        if entry.is_err() {
-            continue;
+    let parent: &Path = path.parent()?;
+
+    return find_config(parent);
+}
+
+/// Get configuration from config file content, see [`ConfigFile::from_content`].
+pub fn get_content_config(content: &str) -> Result<Configuration, ConfigError> {
+    return ConfigFile::from_content(content)?.get_config(None);
+}
+
+/// Parsed config file, with overrides for paths matching globs.
+#[derive(Debug)]
+pub struct ConfigFile {
+    pub path: Option<PathBuf>,
+    entries: Vec<ConfigEntry>,
+    overrides: Vec<ConfigOverride>,
+}
+
+#[derive(Debug)]
+struct ConfigEntry {
+    line: usize,
+    key: String,
+    value: ConfigValue,
+}
+
+#[derive(Debug)]
+struct ConfigOverride {
+    files: Vec<String>,
+    entries: Vec<ConfigEntry>,
+}
+
+impl ConfigFile {
+    pub fn from_path(file: PathBuf) -> Result<ConfigFile, ConfigError> {
+        let content: String = fs::read_to_string(&file).map_err(|e| ConfigError {
+            path: Some(file.clone()),
+            line: None,
+            message: e.to_string(),
+        })?;
+
+        let mut config_file: ConfigFile = ConfigFile::from_content(&content).map_err(|mut e| {
+            e.path = Some(file.clone());
+            e
+        })?;
+        config_file.path = Some(file);
+        return Ok(config_file);
+    }
+
+    /// Parse config file content.
+    ///
+    /// Each line is a `key = value` pair or a `key` (enabling it), `#` starts a comment.
+    /// Keys can be in a `[tool.sqlfmt]` section, `[[overrides]]` sections apply to `files` globs.
+    pub fn from_content(content: &str) -> Result<ConfigFile, ConfigError> {
+        let mut config_file: ConfigFile = ConfigFile {
+            path: None,
+            entries: vec![],
+            overrides: vec![],
+        };
+
+        let chars: Vec<char> = content.chars().collect();
+        let mut override_line: usize = 0;
+        let mut start: usize = 0;
+        let mut line_number: usize = 0;
+        while start < chars.len() {
+            line_number += 1;
+            let entry_line: usize = line_number;
+            let get_error = |message: String| ConfigError {
+                path: None,
+                line: Some(entry_line),
+                message,
+            };
+
+            let line: String = chars[start..].iter().take_while(|c| **c != '\n').collect();
+            if line.trim_start().starts_with('[') {
+                start += line.chars().count() + 1;
+                config_file.check_override(override_line)?;
+                let section: &str = line.split('#').next().unwrap_or("").trim();
+                match section {
+                    "[tool.sqlfmt]" => {
+                        if !config_file.overrides.is_empty() {
+                            return Err(get_error(format!(
+                                "Section '{section}' must be before overrides"
+                            )));
+                        }
+                    }
+                    "[[overrides]]" | "[[tool.sqlfmt.overrides]]" => {
+                        override_line = entry_line;
+                        config_file.overrides.push(ConfigOverride {
+                            files: vec![],
+                            entries: vec![],
+                        });
+                    }
+                    _ => return Err(get_error(format!("Unknown section '{section}'"))),
+                }
+                continue;
+            }
+
+            // values like multi-line lists can continue on the next lines
+            let (key_value, len): (Option<(String, ConfigValue)>, usize) =
+                get_config_line(&chars[start..]).map_err(get_error)?;
+            line_number += chars[start..start + len]
+                .iter()
+                .filter(|c| **c == '\n')
+                .count()
+                .saturating_sub(1);
+            start += len;
+            let Some((key, value)) = key_value else {
+                continue;
+            };
+
+            let entry: ConfigEntry = ConfigEntry {
+                line: entry_line,
+                key,
+                value,
+            };
+
+            match config_file.overrides.last_mut() {
+                Some(config_override) => {
+                    if entry.key == "files" {
+                        config_override.files = match entry.value {
+                            ConfigValue::Quoted(glob) => vec![glob],
+                            ConfigValue::Array(globs) => globs,
+                            _ => {
+                                return Err(get_error(String::from(
+                                    "Invalid value for files (must be a quoted glob or list of quoted globs)",
+                                )));
+                            }
+                        };
+                        continue;
+                    }
+                    config_override.entries.push(entry);
+                }
+                None => config_file.entries.push(entry),
+            }
         }
-        let entry: DirEntry = entry.unwrap();
-        if entry.file_name() == CONFIG_FILE_NAME {
-            return get_file_config(entry.path());
+        config_file.check_override(override_line)?;
+
+        // validate all entries, even if overrides are not used
+        let mut config: Configuration = Configuration::new();
+        apply_config_entries(&mut config, &config_file.entries)?;
+        for config_override in &config_file.overrides {
+            apply_config_entries(&mut config, &config_override.entries)?;
         }
+
+        return Ok(config_file);
     }
 
-    let parent: Option<&Path> = path.parent();
-    if parent.is_none() {
-        return None;
+    fn check_override(&self, override_line: usize) -> Result<(), ConfigError> {
+        if self.overrides.last().is_some_and(|o| o.files.is_empty()) {
+            return Err(ConfigError {
+                path: None,
+                line: Some(override_line),
+                message: String::from("Missing files for override"),
+            });
+        }
+        return Ok(());
+    }
+
+    /// Get configuration for the input path, applying matching overrides in order.
+    pub fn get_config(&self, input: Option<&Path>) -> Result<Configuration, ConfigError> {
+        let mut config: Configuration = Configuration::new();
+        apply_config_entries(&mut config, &self.entries).map_err(|e| self.get_error(e))?;
+        for config_override in self.get_overrides(input) {
+            apply_config_entries(&mut config, &config_override.entries)
+                .map_err(|e| self.get_error(e))?;
+        }
+
+        return Ok(config);
+    }
+
+    /// Check if the key is set for the input path, in the file or a matching override.
+    pub fn has_key(&self, key: &str, input: Option<&Path>) -> bool {
+        return self
+            .entries
+            .iter()
+            .chain(self.get_overrides(input).flat_map(|o| &o.entries))
+            .any(|e| e.key == key);
+    }
+
+    /// Get overrides with globs matching the input path, relative to the config file.
+    fn get_overrides(&self, input: Option<&Path>) -> impl Iterator<Item = &ConfigOverride> {
+        let relative_path: Option<String> = match (input, &self.path) {
+            (Some(input), Some(path)) => {
+                let root: PathBuf = get_absolute_path(path)
+                    .parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_default();
+                let input: PathBuf = get_absolute_path(input);
+                input
+                    .starts_with(&root)
+                    .then(|| files::get_relative_path(&root, &input))
+            }
+            (Some(input), None) => Some(files::get_relative_path(Path::new(""), input)),
+            (None, _) => None,
+        };
+
+        return self.overrides.iter().filter(move |o| {
+            relative_path.as_ref().is_some_and(|relative_path| {
+                o.files
+                    .iter()
+                    .any(|g| files::glob_match_path(g, relative_path))
+            })
+        });
     }
 
-    return find_config(parent.unwrap());
+    fn get_error(&self, mut error: ConfigError) -> ConfigError {
+        error.path = self.path.clone();
+        return error;
+    }
 }
 
-fn get_file_config(file: PathBuf) -> Option<Configuration> {
-    let content: Result<String, std::io::Error> = fs::read_to_string(file);
-    if content.is_err() {
-        return None;
+fn get_absolute_path(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+        return path;
     }
-    let content: String = content.unwrap();
+    return std::path::absolute(path).unwrap_or(path.to_path_buf());
+}
 
-    let mut config: Configuration = Configuration::new();
+/// Apply config entries, keys not set keep their current value.
+fn apply_config_entries(
+    config: &mut Configuration,
+    entries: &[ConfigEntry],
+) -> Result<(), ConfigError> {
+    // applied after all entries, same order as arguments
+    let mut upper: Option<bool> = None;
+    let mut lower: Option<bool> = None;
+    let mut consistent: Option<bool> = None;
+    let mut tabs: Option<bool> = None;
+    let mut spaces: Option<u16> = None;
 
-    for line in content.lines() {
-        if line.contains("newlines") {
-            config.newlines = true;
-        } else if line.contains("comment_pre_space") {
-            config.comment_pre_space = true;
-        } else if line.contains("align_text_groups") {
-            config.align_text_groups = true;
-        } else if line.contains("upper") {
-            config.case = ConfigCase::Uppercase
-        } else if line.contains("lower") {
-            config.case = ConfigCase::Lowercase
-        } else if line.contains("tabs") {
-            config.tabs = ConfigTab::Tab;
-        } else if line.starts_with("spaces") {
-            if let Some(n) = line.split("=").last() {
-                if let Ok(spaces) = n.parse::<u8>() {
-                    config.tabs = ConfigTab::Space(spaces);
+    for entry in entries {
+        let get_error = |message: String| ConfigError {
+            path: None,
+            line: Some(entry.line),
+            message,
+        };
+
+        let key: &str = entry.key.as_str();
+        let value: &ConfigValue = &entry.value;
+        match key {
+            "newlines" => config.newlines = get_config_bool(key, value).map_err(get_error)?,
+            "comment_pre_space" => {
+                config.comment_pre_space = get_config_bool(key, value).map_err(get_error)?
+            }
+            "align_text_groups" => {
+                config.align_text_groups = get_config_bool(key, value).map_err(get_error)?
+            }
+            "collapse_short_statements" => {
+                config.collapse_short_statements = get_config_bool(key, value).map_err(get_error)?
+            }
+            "collapse_short_clauses" => {
+                config.collapse_short_clauses = get_config_bool(key, value).map_err(get_error)?
+            }
+            "align_aliases" => {
+                config.align_aliases = get_config_bool(key, value).map_err(get_error)?
+            }
+            "align_assignments" => {
+                config.align_assignments = get_config_bool(key, value).map_err(get_error)?
+            }
+            "align_case_then" => {
+                config.align_case_then = get_config_bool(key, value).map_err(get_error)?
+            }
+            "wrap_comments" => {
+                config.wrap_comments = get_config_bool(key, value).map_err(get_error)?
+            }
+            "indent_block_comments" => {
+                config.indent_block_comments = get_config_bool(key, value).map_err(get_error)?
+            }
+            "block_comment_gutters" => {
+                config.block_comment_gutters = get_config_bool(key, value).map_err(get_error)?
+            }
+            "upper" => upper = Some(get_config_bool(key, value).map_err(get_error)?),
+            "lower" => lower = Some(get_config_bool(key, value).map_err(get_error)?),
+            "consistent" => consistent = Some(get_config_bool(key, value).map_err(get_error)?),
+            "identifier_case" => {
+                config.identifier_case = get_config_case(key, value).map_err(get_error)?
+            }
+            "function_case" => {
+                config.function_case = Some(get_config_case(key, value).map_err(get_error)?)
+            }
+            "datatype_case" => {
+                config.datatype_case = Some(get_config_case(key, value).map_err(get_error)?)
+            }
+            "tabs" => tabs = Some(get_config_bool(key, value).map_err(get_error)?),
+            "spaces" => spaces = Some(get_config_u16(key, value).map_err(get_error)?),
+            "chars" => config.chars = get_config_u16(key, value).map_err(get_error)?,
+            "comment_chars" => {
+                config.comment_chars = Some(get_config_u16(key, value).map_err(get_error)?)
+            }
+            "paren_chars" => {
+                config.paren_chars = Some(get_config_u16(key, value).map_err(get_error)?)
+            }
+            "comma_style" => {
+                let name: &str = value.as_str();
+                config.comma_style = ConfigCommaStyle::from_name(name).ok_or_else(|| {
+                    get_error(format!(
+                        "Invalid comma style '{name}' (must be leading or trailing)"
+                    ))
+                })?;
+            }
+            "layout" => {
+                let name: &str = value.as_str();
+                config.layout = ConfigLayout::from_name(name).ok_or_else(|| {
+                    get_error(format!(
+                        "Invalid layout '{name}' (must be standard or river)"
+                    ))
+                })?;
+            }
+            "comment_style" => {
+                let name: &str = value.as_str();
+                config.comment_style = ConfigCommentStyle::from_name(name).ok_or_else(|| {
+                    get_error(format!(
+                        "Invalid comment style '{name}' (must be preserve, line or block)"
+                    ))
+                })?;
+            }
+            "line_endings" => {
+                let name: &str = value.as_str();
+                config.line_endings = ConfigLineEndings::from_name(name).ok_or_else(|| {
+                    get_error(format!(
+                        "Invalid line endings '{name}' (must be auto, lf or crlf)"
+                    ))
+                })?;
+            }
+            "dialect" => {
+                let name: &str = value.as_str();
+                config.dialect = ConfigDialect::from_name(name).ok_or_else(|| {
+                    get_error(format!(
+                        "Invalid dialect '{name}' (must be generic, postgres, mysql, tsql, sqlite, bigquery or snowflake)"
+                    ))
+                })?;
+            }
+            _ => return Err(get_error(format!("Unknown key '{key}'"))),
+        }
+    }
+
+    if upper == Some(true) {
+        config.case = ConfigCase::Uppercase;
+    } else if lower == Some(true) {
+        config.case = ConfigCase::Lowercase;
+    } else if consistent == Some(true) {
+        config.case = ConfigCase::Consistent;
+    } else if (upper == Some(false) && config.case == ConfigCase::Uppercase)
+        || (lower == Some(false) && config.case == ConfigCase::Lowercase)
+        || (consistent == Some(false) && config.case == ConfigCase::Consistent)
+    {
+        config.case = ConfigCase::Unchanged;
+    }
+
+    if let Some(spaces) = spaces {
+        config.tabs = ConfigTab::Space(spaces);
+    } else if tabs == Some(true) {
+        config.tabs = ConfigTab::Tab;
+    } else if tabs == Some(false) && config.tabs == ConfigTab::Tab {
+        config.tabs = ConfigTab::Space(4);
+    }
+
+    return Ok(());
+}
+
+/// Value of a config line, `None` if only the key is provided.
+#[derive(Debug, PartialEq)]
+enum ConfigValue {
+    None,
+    Text(String),
+    Quoted(String),
+    Array(Vec<String>),
+}
+
+impl ConfigValue {
+    fn as_str(&self) -> &str {
+        return match self {
+            ConfigValue::None | ConfigValue::Array(_) => "",
+            ConfigValue::Text(value) | ConfigValue::Quoted(value) => value,
+        };
+    }
+}
+
+/// Get key and value of a config line, `None` if empty or only a comment,
+/// and the count of chars used including the newline.
+fn get_config_line(chars: &[char]) -> Result<(Option<(String, ConfigValue)>, usize), String> {
+    let mut key: String = String::new();
+    let mut value: Option<ConfigValue> = None;
+    let mut i: usize = 0;
+    while i < chars.len() {
+        let c: char = chars[i];
+        i += 1;
+
+        if c == '\n' {
+            break;
+        }
+        if c == '#' {
+            i += get_config_comment_len(&chars[i..]);
+            break;
+        }
+
+        match &mut value {
+            None => {
+                if c == '=' {
+                    value = Some(ConfigValue::None);
+                } else {
+                    key.push(c);
+                }
+            }
+            Some(ConfigValue::None) => {
+                if c == '"' || c == '\'' {
+                    let (quoted, len): (String, usize) = get_config_quoted(&chars[i..], c)?;
+                    i += len;
+                    value = Some(ConfigValue::Quoted(quoted));
+                } else if c == '[' {
+                    let (array, len): (Vec<String>, usize) = get_config_array(&chars[i..])?;
+                    i += len;
+                    value = Some(ConfigValue::Array(array));
+                } else if !c.is_whitespace() {
+                    value = Some(ConfigValue::Text(String::from(c)));
                 }
             }
-        } else if line.starts_with("chars") {
-            if let Some(n) = line.split("=").last() {
-                if let Ok(chars) = n.parse::<u8>() {
-                    config.chars = chars;
+            Some(ConfigValue::Text(text)) => text.push(c),
+            Some(ConfigValue::Quoted(_)) | Some(ConfigValue::Array(_)) => {
+                if !c.is_whitespace() {
+                    return Err(format!("Unexpected '{c}' after quoted value"));
                 }
             }
         }
     }
 
-    return Some(config);
+    let key: String = key.trim().to_string();
+    if key.is_empty() {
+        if value.is_some() {
+            return Err(String::from("Missing key before '='"));
+        }
+        return Ok((None, i));
+    }
+
+    if !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("Invalid key '{key}'"));
+    }
+
+    let value: ConfigValue = match value {
+        None => ConfigValue::None,
+        Some(ConfigValue::None) => return Err(format!("Missing value for {key}")),
+        Some(ConfigValue::Text(text)) => ConfigValue::Text(text.trim().to_string()),
+        Some(quoted) => quoted,
+    };
+
+    return Ok((Some((key, value)), i));
+}
+
+/// Get the count of chars in a comment after the `#`, including the newline.
+fn get_config_comment_len(chars: &[char]) -> usize {
+    return chars
+        .iter()
+        .position(|c| *c == '\n')
+        .map_or(chars.len(), |p| p + 1);
+}
+
+/// Get list of quoted strings after the opening bracket, and the count of chars used including the closing bracket.
+///
+/// Lists can span lines, with comments and a trailing comma.
+fn get_config_array(chars: &[char]) -> Result<(Vec<String>, usize), String> {
+    let mut values: Vec<String> = vec![];
+    let mut i: usize = 0;
+    while i < chars.len() {
+        let c: char = chars[i];
+        i += 1;
+
+        match c {
+            ']' => return Ok((values, i)),
+            '"' | '\'' => {
+                let (quoted, len): (String, usize) = get_config_quoted(&chars[i..], c)?;
+                i += len;
+                values.push(quoted);
+            }
+            ',' => (),
+            '#' => i += get_config_comment_len(&chars[i..]),
+            c if c.is_whitespace() => (),
+            c => return Err(format!("Unexpected '{c}' in list (values must be quoted)")),
+        }
+    }
+    return Err(String::from("Unterminated list"));
+}
+
+/// Get quoted string after the opening quote, and the count of chars used including the closing quote.
+///
+/// Double quoted strings support TOML escapes, single quoted strings are literal.
+fn get_config_quoted(chars: &[char], quote: char) -> Result<(String, usize), String> {
+    let mut value: String = String::new();
+    let mut i: usize = 0;
+    while i < chars.len() {
+        let c: char = chars[i];
+        i += 1;
+
+        if c == quote {
+            return Ok((value, i));
+        }
+        if c == '\n' {
+            break;
+        }
+
+        if c == '\\' && quote == '"' && i < chars.len() {
+            let escape: char = chars[i];
+            i += 1;
+            value.push(match escape {
+                'b' => '\u{8}',
+                't' => '\t',
+                'n' => '\n',
+                'f' => '\u{c}',
+                'r' => '\r',
+                '"' | '\\' => escape,
+                'u' | 'U' => {
+                    let len: usize = if escape == 'u' { 4 } else { 8 };
+                    let hex: String = chars[i..]
+                        .iter()
+                        .take(len)
+                        .take_while(|c| c.is_ascii_hexdigit())
+                        .collect();
+                    i += hex.chars().count();
+                    u32::from_str_radix(&hex, 16)
+                        .ok()
+                        .filter(|_| hex.len() == len)
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("Invalid escape '\\{escape}{hex}'"))?
+                }
+                _ => return Err(format!("Invalid escape '\\{escape}'")),
+            });
+            continue;
+        }
+
+        value.push(c);
+    }
+    return Err(String::from("Unterminated quoted value"));
+}
+
+fn get_config_bool(key: &str, value: &ConfigValue) -> Result<bool, String> {
+    return match value {
+        ConfigValue::None => Ok(true),
+        ConfigValue::Text(text) if text == "true" => Ok(true),
+        ConfigValue::Text(text) if text == "false" => Ok(false),
+        _ => Err(format!(
+            "Invalid value '{}' for {key} (must be true or false)",
+            value.as_str()
+        )),
+    };
 }
 
+fn get_config_case(key: &str, value: &ConfigValue) -> Result<ConfigCase, String> {
+    let name: &str = value.as_str();
+    return ConfigCase::from_name(name).ok_or_else(|| {
+        format!("Invalid value '{name}' for {key} (must be upper, lower, preserve or consistent)")
+    });
+}
+
+fn get_config_u16(key: &str, value: &ConfigValue) -> Result<u16, String> {
+    if let ConfigValue::Text(text) = value
+        && let Ok(n) = text.parse::<u16>()
+    {
+        return Ok(n);
+    }
+    return Err(format!(
+        "Invalid value '{}' for {key} (must be 0-65535)",
+        value.as_str()
+    ));
+}
+
+/// Error found in a config file.
 #[derive(Debug, PartialEq, Eq)]
+pub struct ConfigError {
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{line}:")?;
+        }
+        if self.path.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigCase {
     Uppercase,
     Lowercase,
     Unchanged,
+    /// Case used by most of the input tokens it applies to.
+    Consistent,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+impl ConfigCase {
+    pub fn from_name(name: &str) -> Option<ConfigCase> {
+        return match name.to_lowercase().as_str() {
+            "upper" => Some(ConfigCase::Uppercase),
+            "lower" => Some(ConfigCase::Lowercase),
+            "preserve" => Some(ConfigCase::Unchanged),
+            "consistent" => Some(ConfigCase::Consistent),
+            _ => None,
+        };
+    }
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigCase::Uppercase => "upper",
+            ConfigCase::Lowercase => "lower",
+            ConfigCase::Unchanged => "preserve",
+            ConfigCase::Consistent => "consistent",
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigTab {
     Tab,
-    Space(u8),
+    Space(u16),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommaStyle {
+    Leading,
+    Trailing,
+}
+
+impl ConfigCommaStyle {
+    pub fn from_name(name: &str) -> Option<ConfigCommaStyle> {
+        return match name.to_lowercase().as_str() {
+            "leading" => Some(ConfigCommaStyle::Leading),
+            "trailing" => Some(ConfigCommaStyle::Trailing),
+            _ => None,
+        };
+    }
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigCommaStyle::Leading => "leading",
+            ConfigCommaStyle::Trailing => "trailing",
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLayout {
+    Standard,
+    River,
+}
+
+impl ConfigLayout {
+    pub fn from_name(name: &str) -> Option<ConfigLayout> {
+        return match name.to_lowercase().as_str() {
+            "standard" => Some(ConfigLayout::Standard),
+            "river" => Some(ConfigLayout::River),
+            _ => None,
+        };
+    }
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigLayout::Standard => "standard",
+            ConfigLayout::River => "river",
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommentStyle {
+    Preserve,
+    /// Convert block comments ending a line to `--` comments.
+    Line,
+    /// Convert `--` comments to block comments.
+    Block,
+}
+
+impl ConfigCommentStyle {
+    pub fn from_name(name: &str) -> Option<ConfigCommentStyle> {
+        return match name.to_lowercase().as_str() {
+            "preserve" => Some(ConfigCommentStyle::Preserve),
+            "line" => Some(ConfigCommentStyle::Line),
+            "block" => Some(ConfigCommentStyle::Block),
+            _ => None,
+        };
+    }
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigCommentStyle::Preserve => "preserve",
+            ConfigCommentStyle::Line => "line",
+            ConfigCommentStyle::Block => "block",
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLineEndings {
+    /// Use the line ending found the most in the input.
+    Auto,
+    Lf,
+    Crlf,
+}
+
+impl ConfigLineEndings {
+    pub fn from_name(name: &str) -> Option<ConfigLineEndings> {
+        return match name.to_lowercase().as_str() {
+            "auto" => Some(ConfigLineEndings::Auto),
+            "lf" => Some(ConfigLineEndings::Lf),
+            "crlf" => Some(ConfigLineEndings::Crlf),
+            _ => None,
+        };
+    }
+
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigLineEndings::Auto => "auto",
+            ConfigLineEndings::Lf => "lf",
+            ConfigLineEndings::Crlf => "crlf",
+        };
+    }
+
+    /// Get line ending characters, auto being resolved from input first.
+    pub fn get_value(&self) -> &'static str {
+        return match self {
+            ConfigLineEndings::Crlf => "\r\n",
+            _ => "\n",
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigDialect {
+    Generic,
+    Postgres,
+    Mysql,
+    Tsql,
+    Sqlite,
+    Bigquery,
+    Snowflake,
+}
+
+impl ConfigDialect {
+    pub fn name(&self) -> &'static str {
+        return match self {
+            ConfigDialect::Generic => "generic",
+            ConfigDialect::Postgres => "postgres",
+            ConfigDialect::Mysql => "mysql",
+            ConfigDialect::Tsql => "tsql",
+            ConfigDialect::Sqlite => "sqlite",
+            ConfigDialect::Bigquery => "bigquery",
+            ConfigDialect::Snowflake => "snowflake",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<ConfigDialect> {
+        return match name.to_lowercase().as_str() {
+            "generic" => Some(ConfigDialect::Generic),
+            "postgres" => Some(ConfigDialect::Postgres),
+            "mysql" => Some(ConfigDialect::Mysql),
+            "tsql" => Some(ConfigDialect::Tsql),
+            "sqlite" => Some(ConfigDialect::Sqlite),
+            "bigquery" => Some(ConfigDialect::Bigquery),
+            "snowflake" => Some(ConfigDialect::Snowflake),
+            _ => None,
+        };
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -164,13 +1235,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -180,13 +1260,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, true);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -196,13 +1285,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, true);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -212,13 +1310,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, true);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -228,13 +1335,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -244,13 +1360,47 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Lowercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(4));
+        assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
+    }
+
+    #[test]
+    fn test_get_configuration_consistent() {
+        let args: Vec<String> = vec![String::from("--consistent")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+
+        let config: Configuration = Configuration::from(&arguments).unwrap();
+        assert_eq!(config.newlines, false);
+        assert_eq!(config.comment_pre_space, false);
+        assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
+        assert_eq!(config.case, ConfigCase::Consistent);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -260,13 +1410,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Tab);
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -276,13 +1435,22 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(2));
         assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
     }
 
     #[test]
@@ -292,12 +1460,546 @@ mod tests {
         assert_eq!(arguments.is_ok(), true);
         let arguments: Arguments = arguments.unwrap();
 
-        let config: Configuration = Configuration::from(&arguments);
+        let config: Configuration = Configuration::from(&arguments).unwrap();
+        assert_eq!(config.newlines, false);
+        assert_eq!(config.comment_pre_space, false);
+        assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
+        assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(4));
+        assert_eq!(config.chars, 120);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Generic);
+    }
+
+    #[test]
+    fn test_get_configuration_comment_paren_chars() {
+        let args: Vec<String> = vec![
+            String::from("-c"),
+            String::from("300"),
+            String::from("--paren-chars"),
+            String::from("40"),
+        ];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+
+        let config: Configuration = Configuration::from(&arguments).unwrap();
+        assert_eq!(config.chars, 300);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, Some(40));
+        assert_eq!(config.get_comment_chars(), 300);
+        assert_eq!(config.get_paren_chars(), 40);
+    }
+
+    #[test]
+    fn test_get_configuration_dialect() {
+        let args: Vec<String> = vec![String::from("--dialect"), String::from("bigquery")];
+        let arguments: Result<Arguments, &str> = Arguments::from(args.into_iter());
+        assert_eq!(arguments.is_ok(), true);
+        let arguments: Arguments = arguments.unwrap();
+
+        let config: Configuration = Configuration::from(&arguments).unwrap();
         assert_eq!(config.newlines, false);
         assert_eq!(config.comment_pre_space, false);
         assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
         assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
         assert_eq!(config.tabs, ConfigTab::Space(4));
+        assert_eq!(config.chars, 80);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Bigquery);
+    }
+
+    #[test]
+    fn test_get_configuration_builder() {
+        let config: Configuration = Configuration::builder()
+            .newlines(true)
+            .comment_pre_space(true)
+            .align_text_groups(true)
+            .collapse_short_statements(true)
+            .case(ConfigCase::Uppercase)
+            .tabs(ConfigTab::Tab)
+            .chars(120)
+            .dialect(ConfigDialect::Tsql)
+            .build();
+        assert_eq!(config.newlines, true);
+        assert_eq!(config.comment_pre_space, true);
+        assert_eq!(config.align_text_groups, true);
+        assert_eq!(config.collapse_short_statements, true);
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Tab);
+        assert_eq!(config.chars, 120);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Tsql);
+    }
+
+    #[test]
+    fn test_get_configuration_builder_default() {
+        let config: Configuration = Configuration::builder().build();
+        assert_eq!(config, Configuration::new());
+    }
+
+    #[test]
+    fn test_get_content_config_legacy() {
+        let config: Configuration = get_content_config(
+            "newlines\ncomment_pre_space\nalign_text_groups\nupper\ntabs\nspaces=2\nchars=120\ndialect=mysql\n",
+        )
+        .unwrap();
+        assert_eq!(config.newlines, true);
+        assert_eq!(config.comment_pre_space, true);
+        assert_eq!(config.align_text_groups, true);
+        assert_eq!(config.collapse_short_statements, false);
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(2));
         assert_eq!(config.chars, 120);
+        assert_eq!(config.comment_chars, None);
+        assert_eq!(config.paren_chars, None);
+        assert_eq!(config.comma_style, ConfigCommaStyle::Trailing);
+        assert_eq!(config.layout, ConfigLayout::Standard);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Preserve);
+        assert_eq!(config.line_endings, ConfigLineEndings::Auto);
+        assert_eq!(config.dialect, ConfigDialect::Mysql);
+    }
+
+    #[test]
+    fn test_get_content_config_key_value() {
+        let config: Configuration = get_content_config(
+            r#"
+# don't use uppercase
+newlines = true  # replace newlines
+comment_pre_space = false
+lower = true
+upper = false
+dialect = "postgres"
+chars = 300
+comment_chars = 100
+paren_chars = 40
+comma_style = leading
+layout = river
+comment_style = block
+line_endings = "crlf"
+collapse_short_clauses = true
+identifier_case = lower
+function_case = "upper"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.newlines, true);
+        assert_eq!(config.comment_pre_space, false);
+        assert_eq!(config.align_text_groups, false);
+        assert_eq!(config.collapse_short_statements, false);
+        assert_eq!(config.collapse_short_clauses, true);
+        assert_eq!(config.align_aliases, false);
+        assert_eq!(config.align_assignments, false);
+        assert_eq!(config.align_case_then, false);
+        assert_eq!(config.wrap_comments, false);
+        assert_eq!(config.indent_block_comments, false);
+        assert_eq!(config.block_comment_gutters, false);
+        assert_eq!(config.case, ConfigCase::Lowercase);
+        assert_eq!(config.tabs, ConfigTab::Space(4));
+        assert_eq!(config.chars, 300);
+        assert_eq!(config.comment_chars, Some(100));
+        assert_eq!(config.paren_chars, Some(40));
+        assert_eq!(config.comma_style, ConfigCommaStyle::Leading);
+        assert_eq!(config.layout, ConfigLayout::River);
+        assert_eq!(config.comment_style, ConfigCommentStyle::Block);
+        assert_eq!(config.line_endings, ConfigLineEndings::Crlf);
+        assert_eq!(config.identifier_case, ConfigCase::Lowercase);
+        assert_eq!(config.function_case, Some(ConfigCase::Uppercase));
+        assert_eq!(config.datatype_case, None);
+        assert_eq!(config.get_datatype_case(), &ConfigCase::Lowercase);
+        assert_eq!(config.dialect, ConfigDialect::Postgres);
+    }
+
+    #[test]
+    fn test_get_content_config_order() {
+        assert_eq!(
+            get_content_config("upper\nlower").unwrap().case,
+            ConfigCase::Uppercase
+        );
+        assert_eq!(
+            get_content_config("lower\nupper").unwrap().case,
+            ConfigCase::Uppercase
+        );
+        assert_eq!(
+            get_content_config("consistent\nlower").unwrap().case,
+            ConfigCase::Lowercase
+        );
+        assert_eq!(
+            get_content_config("spaces = 2\ntabs").unwrap().tabs,
+            ConfigTab::Space(2)
+        );
+    }
+
+    #[test]
+    fn test_get_content_config_errors() {
+        assert_eq!(
+            get_content_config("newlines\nupercase = true"),
+            Err(ConfigError {
+                path: None,
+                line: Some(2),
+                message: String::from("Unknown key 'upercase'"),
+            })
+        );
+        assert_eq!(
+            get_content_config("\n\nnewlines = yes")
+                .unwrap_err()
+                .to_string(),
+            "3: Invalid value 'yes' for newlines (must be true or false)"
+        );
+        assert_eq!(
+            get_content_config("spaces = 70000").unwrap_err().message,
+            "Invalid value '70000' for spaces (must be 0-65535)"
+        );
+        assert_eq!(
+            get_content_config("chars = \"80\"").unwrap_err().message,
+            "Invalid value '80' for chars (must be 0-65535)"
+        );
+        assert_eq!(
+            get_content_config("comma_style = both")
+                .unwrap_err()
+                .message,
+            "Invalid comma style 'both' (must be leading or trailing)"
+        );
+        assert_eq!(
+            get_content_config("function_case = title")
+                .unwrap_err()
+                .message,
+            "Invalid value 'title' for function_case (must be upper, lower, preserve or consistent)"
+        );
+        assert_eq!(
+            get_content_config("layout = mountain").unwrap_err().message,
+            "Invalid layout 'mountain' (must be standard or river)"
+        );
+        assert_eq!(
+            get_content_config("comment_style = hash")
+                .unwrap_err()
+                .message,
+            "Invalid comment style 'hash' (must be preserve, line or block)"
+        );
+        assert_eq!(
+            get_content_config("line_endings = cr").unwrap_err().message,
+            "Invalid line endings 'cr' (must be auto, lf or crlf)"
+        );
+        assert_eq!(
+            get_content_config("dialect = oracle").unwrap_err().message,
+            "Invalid dialect 'oracle' (must be generic, postgres, mysql, tsql, sqlite, bigquery or snowflake)"
+        );
+        assert_eq!(
+            get_content_config("dialect = \"mysql").unwrap_err().message,
+            "Unterminated quoted value"
+        );
+        assert_eq!(
+            get_content_config("dialect = 'mysql' x")
+                .unwrap_err()
+                .message,
+            "Unexpected 'x' after quoted value"
+        );
+        assert_eq!(
+            get_content_config("chars =").unwrap_err().message,
+            "Missing value for chars"
+        );
+        assert_eq!(
+            get_content_config("= 80").unwrap_err().message,
+            "Missing key before '='"
+        );
+        assert_eq!(
+            get_content_config("new lines").unwrap_err().message,
+            "Invalid key 'new lines'"
+        );
+    }
+
+    #[test]
+    fn test_config_error_display() {
+        let error: ConfigError = ConfigError {
+            path: Some(PathBuf::from("dir/.sqlfmt")),
+            line: Some(4),
+            message: String::from("Unknown key 'x'"),
+        };
+        assert_eq!(error.to_string(), "dir/.sqlfmt:4: Unknown key 'x'");
+    }
+
+    #[test]
+    fn test_config_file_overrides() {
+        let config_file: ConfigFile = ConfigFile::from_content(
+            r#"
+[tool.sqlfmt]
+newlines = true
+upper = true
+spaces = 2
+
+[[overrides]]
+files = ["migrations/**"]  # t-sql migrations
+dialect = "tsql"
+tabs = true
+
+[[tool.sqlfmt.overrides]]
+files = "analytics"
+dialect = "bigquery"
+upper = false
+"#,
+        )
+        .unwrap();
+
+        let config: Configuration = config_file.get_config(None).unwrap();
+        assert_eq!(config.newlines, true);
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(2));
+        assert_eq!(config.dialect, ConfigDialect::Generic);
+
+        let config: Configuration = config_file
+            .get_config(Some(Path::new("migrations/v1/a.sql")))
+            .unwrap();
+        assert_eq!(config.newlines, true);
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Tab);
+        assert_eq!(config.dialect, ConfigDialect::Tsql);
+
+        let config: Configuration = config_file
+            .get_config(Some(Path::new("src/analytics/a.sql")))
+            .unwrap();
+        assert_eq!(config.case, ConfigCase::Unchanged);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(2));
+        assert_eq!(config.dialect, ConfigDialect::Bigquery);
+
+        let config: Configuration = config_file
+            .get_config(Some(Path::new("src/a.sql")))
+            .unwrap();
+        assert_eq!(config.dialect, ConfigDialect::Generic);
+    }
+
+    #[test]
+    fn test_config_file_errors() {
+        assert_eq!(
+            ConfigFile::from_content("[overrides]").unwrap_err().message,
+            "Unknown section '[overrides]'"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nupper\n[[overrides]]\nfiles = ['a']")
+                .unwrap_err(),
+            ConfigError {
+                path: None,
+                line: Some(1),
+                message: String::from("Missing files for override"),
+            }
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = [a]")
+                .unwrap_err()
+                .message,
+            "Unexpected 'a' in list (values must be quoted)"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = ['a'\n")
+                .unwrap_err()
+                .message,
+            "Unterminated list"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = true")
+                .unwrap_err()
+                .message,
+            "Invalid value for files (must be a quoted glob or list of quoted globs)"
+        );
+        assert_eq!(
+            ConfigFile::from_content("files = ['a']")
+                .unwrap_err()
+                .message,
+            "Unknown key 'files'"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = ['a']\nchars = x").unwrap_err(),
+            ConfigError {
+                path: None,
+                line: Some(3),
+                message: String::from("Invalid value 'x' for chars (must be 0-65535)"),
+            }
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = ['a']\n[tool.sqlfmt]")
+                .unwrap_err()
+                .message,
+            "Section '[tool.sqlfmt]' must be before overrides"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = ['a',\n'b'\n").unwrap_err(),
+            ConfigError {
+                path: None,
+                line: Some(2),
+                message: String::from("Unterminated list"),
+            }
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = [\"a\\x\"]")
+                .unwrap_err()
+                .message,
+            "Invalid escape '\\x'"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = [\"a\\u12\"]")
+                .unwrap_err()
+                .message,
+            "Invalid escape '\\u12'"
+        );
+        assert_eq!(
+            ConfigFile::from_content("[[overrides]]\nfiles = \"a\nb\"")
+                .unwrap_err()
+                .message,
+            "Unterminated quoted value"
+        );
+    }
+
+    #[test]
+    fn test_config_file_multi_line_list() {
+        let config_file: ConfigFile = ConfigFile::from_content(
+            r#"
+[[overrides]]
+files = [
+  "migrations/**",  # t-sql migrations
+  'C:\legacy\*.sql',
+  "caf\u00e9\t\"x\"",
+]
+dialect = "tsql"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config_file.overrides[0].files,
+            vec![
+                String::from("migrations/**"),
+                String::from("C:\\legacy\\*.sql"),
+                String::from("caf\u{e9}\t\"x\""),
+            ]
+        );
+
+        let config: Configuration = config_file
+            .get_config(Some(Path::new("migrations/v1/a.sql")))
+            .unwrap();
+        assert_eq!(config.dialect, ConfigDialect::Tsql);
+
+        let error: ConfigError =
+            ConfigFile::from_content("[[overrides]]\nfiles = [\n  'a',\n]\nchars = x").unwrap_err();
+        assert_eq!(error.line, Some(5));
+    }
+
+    #[test]
+    fn test_get_configuration_from_path() {
+        let root: PathBuf = std::env::temp_dir().join(format!(
+            "sqlfmt_test_get_configuration_from_path_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("a/migrations")).unwrap();
+        fs::create_dir_all(root.join("b")).unwrap();
+        fs::write(
+            root.join("a/sqlfmt.toml"),
+            "upper\n[[overrides]]\nfiles = 'migrations/*.sql'\ntabs = true\n",
+        )
+        .unwrap();
+        fs::write(root.join("a/.sqlfmt"), "lower\n").unwrap();
+        fs::write(root.join("b/.sqlfmt"), "upper = maybe\n").unwrap();
+
+        let arguments: Arguments =
+            Arguments::from(vec![String::from("-s"), String::from("2")].into_iter()).unwrap();
+
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("a/migrations/1.sql"))).unwrap();
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(2));
+
+        let arguments: Arguments = Arguments::from(vec![].into_iter()).unwrap();
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("a/migrations/1.sql"))).unwrap();
+        assert_eq!(config.tabs, ConfigTab::Tab);
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("a/1.sql"))).unwrap();
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(4));
+
+        let error: ConfigError =
+            Configuration::from_path(&arguments, Some(&root.join("b/1.sql"))).unwrap_err();
+        assert_eq!(error.path.is_some_and(|p| p.ends_with("b/.sqlfmt")), true);
+        assert_eq!(error.line, Some(1));
+
+        let arguments: Arguments =
+            Arguments::from(vec![String::from("--no-config")].into_iter()).unwrap();
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("b/1.sql"))).unwrap();
+        assert_eq!(config, Configuration::new());
+
+        let config_path: String = root.join("a/sqlfmt.toml").display().to_string();
+        let arguments: Arguments =
+            Arguments::from(vec![String::from("--config"), config_path].into_iter()).unwrap();
+        let config_file: Option<ConfigFile> =
+            get_config_file(&arguments, Some(&root.join("b/1.sql"))).unwrap();
+        assert_eq!(
+            config_file.and_then(|f| f.path),
+            Some(root.join("a/sqlfmt.toml"))
+        );
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("b/1.sql"))).unwrap();
+        assert_eq!(config.case, ConfigCase::Uppercase);
+        assert_eq!(config.identifier_case, ConfigCase::Unchanged);
+        assert_eq!(config.tabs, ConfigTab::Space(4));
+        let config: Configuration =
+            Configuration::from_path(&arguments, Some(&root.join("a/migrations/1.sql"))).unwrap();
+        assert_eq!(config.tabs, ConfigTab::Tab);
+
+        let arguments: Arguments = Arguments::from(
+            vec![String::from("--config"), String::from("missing.toml")].into_iter(),
+        )
+        .unwrap();
+        let error: ConfigError = Configuration::from_path(&arguments, None).unwrap_err();
+        assert_eq!(error.path, Some(PathBuf::from("missing.toml")));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_configuration_display() {
+        let config: Configuration = Configuration::new();
+        assert_eq!(
+            config.to_string(),
+            "newlines = false\ncomment_pre_space = false\nalign_text_groups = false\ncollapse_short_statements = false\ncollapse_short_clauses = false\nalign_aliases = false\nalign_assignments = false\nalign_case_then = false\nwrap_comments = false\nindent_block_comments = false\nblock_comment_gutters = false\nupper = false\nlower = false\nconsistent = false\nidentifier_case = \"preserve\"\nspaces = 4\nchars = 80\ncomma_style = \"trailing\"\nlayout = \"standard\"\ncomment_style = \"preserve\"\nline_endings = \"auto\"\ndialect = \"generic\""
+        );
+
+        let config: Configuration = Configuration::builder()
+            .newlines(true)
+            .case(ConfigCase::Lowercase)
+            .tabs(ConfigTab::Tab)
+            .comment_chars(100)
+            .comma_style(ConfigCommaStyle::Leading)
+            .layout(ConfigLayout::River)
+            .identifier_case(ConfigCase::Uppercase)
+            .datatype_case(ConfigCase::Unchanged)
+            .line_endings(ConfigLineEndings::Lf)
+            .dialect(ConfigDialect::Bigquery)
+            .build();
+        assert_eq!(get_content_config(&config.to_string()), Ok(config));
     }
 }
@@ -0,0 +1,122 @@
+#![allow(clippy::needless_return)]
+
+/// Problem found in malformed SQL input.
+///
+/// Lines and columns start at 1 and count characters, ignoring carriage returns.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DiagnosticKind {
+    UnterminatedQuote,
+    UnclosedComment,
+    UnclosedParen,
+    UnmatchedParen,
+}
+
+impl Diagnostic {
+    pub fn new(kind: DiagnosticKind, start: (usize, usize), end: (usize, usize)) -> Diagnostic {
+        Diagnostic {
+            kind,
+            line: start.0,
+            column: start.1,
+            end_line: end.0,
+            end_column: end.1,
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        return match self.kind {
+            DiagnosticKind::UnterminatedQuote => "unterminated quote",
+            DiagnosticKind::UnclosedComment => "unclosed block comment",
+            DiagnosticKind::UnclosedParen => "unclosed parenthesis",
+            DiagnosticKind::UnmatchedParen => "unmatched closing parenthesis",
+        };
+    }
+
+    /// Get a compiler style report of the diagnostic, pointing at the input line.
+    pub fn get_report(&self, level: &str, name: &str, sql: &str) -> String {
+        let line_number: String = self.line.to_string();
+        let gutter: String = " ".repeat(line_number.len());
+        let line: String = sql
+            .split('\n')
+            .nth(self.line - 1)
+            .unwrap_or("")
+            .replace('\r', "");
+        let marker_len: usize = if self.end_line == self.line {
+            std::cmp::max(self.end_column.saturating_sub(self.column), 1)
+        } else {
+            std::cmp::max(line.chars().count() + 1 - self.column, 1)
+        };
+
+        return format!(
+            "{level}: {}\n{gutter}--> {name}:{}:{}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{}\n",
+            self.message(),
+            self.line,
+            self.column,
+            " ".repeat(self.column - 1),
+            "^".repeat(marker_len),
+        );
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message())
+    }
+}
+
+/// Get the line and column after `value`, starting from `position`.
+pub fn get_position_after(position: (usize, usize), value: &str) -> (usize, usize) {
+    let mut position: (usize, usize) = position;
+    for c in value.chars() {
+        if c == '\n' {
+            position = (position.0 + 1, 1);
+        } else {
+            position.1 += 1;
+        }
+    }
+    return position;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_position_after() {
+        assert_eq!(get_position_after((1, 1), ""), (1, 1));
+        assert_eq!(get_position_after((1, 1), "SELECT"), (1, 7));
+        assert_eq!(get_position_after((1, 1), "SELECT\n  1"), (2, 4));
+        assert_eq!(get_position_after((3, 5), "\n"), (4, 1));
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic: Diagnostic = Diagnostic::new(DiagnosticKind::UnclosedParen, (2, 3), (2, 4));
+        assert_eq!(diagnostic.to_string(), "2:3: unclosed parenthesis");
+    }
+
+    #[test]
+    fn test_diagnostic_get_report() {
+        let diagnostic: Diagnostic =
+            Diagnostic::new(DiagnosticKind::UnterminatedQuote, (2, 8), (2, 12));
+        assert_eq!(
+            diagnostic.get_report("error", "in.sql", "SELECT 1;\r\nSELECT 'abc"),
+            "error: unterminated quote\n --> in.sql:2:8\n  |\n2 | SELECT 'abc\n  |        ^^^^\n"
+        );
+
+        let diagnostic: Diagnostic =
+            Diagnostic::new(DiagnosticKind::UnclosedComment, (1, 10), (2, 3));
+        assert_eq!(
+            diagnostic.get_report("warning", "<stdin>", "SELECT 1 /* a\nb"),
+            "warning: unclosed block comment\n --> <stdin>:1:10\n  |\n1 | SELECT 1 /* a\n  |          ^^^^\n"
+        );
+    }
+}
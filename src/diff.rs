@@ -0,0 +1,279 @@
+#![allow(clippy::needless_return)]
+
+const CONTEXT_LINES: usize = 3;
+
+/// Get a unified diff of the changes from `old` to `new`.
+///
+/// File headers are only included when `names` are provided.
+pub fn get_unified_diff(old: &str, new: &str, names: Option<(&str, &str)>) -> String {
+    let old_lines: Vec<&str> = old.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new.split_inclusive('\n').collect();
+    let edits: Vec<Edit> = get_edits(&old_lines, &new_lines);
+
+    let mut result: String = String::new();
+    if !edits.iter().any(|e| e != &Edit::Equal) {
+        return result;
+    }
+
+    if let Some((old_name, new_name)) = names {
+        result.push_str(&format!("--- {old_name}\n+++ {new_name}\n"));
+    }
+
+    for hunk in get_hunks(&edits) {
+        let mut old_start: usize = 0;
+        let mut new_start: usize = 0;
+        for edit in &edits[..hunk.0] {
+            match edit {
+                Edit::Equal => {
+                    old_start += 1;
+                    new_start += 1;
+                }
+                Edit::Delete => old_start += 1,
+                Edit::Insert => new_start += 1,
+            }
+        }
+
+        let mut old_len: usize = 0;
+        let mut new_len: usize = 0;
+        let mut lines: String = String::new();
+        for edit in &edits[hunk.0..hunk.1] {
+            match edit {
+                Edit::Equal => {
+                    push_line(&mut lines, ' ', old_lines[old_start + old_len]);
+                    old_len += 1;
+                    new_len += 1;
+                }
+                Edit::Delete => {
+                    push_line(&mut lines, '-', old_lines[old_start + old_len]);
+                    old_len += 1;
+                }
+                Edit::Insert => {
+                    push_line(&mut lines, '+', new_lines[new_start + new_len]);
+                    new_len += 1;
+                }
+            }
+        }
+
+        result.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            get_hunk_range(old_start, old_len),
+            get_hunk_range(new_start, new_len)
+        ));
+        result.push_str(&lines);
+    }
+
+    return result;
+}
+
+fn push_line(result: &mut String, prefix: char, line: &str) {
+    result.push(prefix);
+    result.push_str(line);
+    if !line.ends_with('\n') {
+        result.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+fn get_hunk_range(start: usize, len: usize) -> String {
+    if len == 0 {
+        return format!("{start},0");
+    }
+    if len == 1 {
+        return format!("{}", start + 1);
+    }
+    return format!("{},{}", start + 1, len);
+}
+
+/// Get start and end edit positions of each hunk, including context.
+fn get_hunks(edits: &[Edit]) -> Vec<(usize, usize)> {
+    let mut hunks: Vec<(usize, usize)> = vec![];
+
+    for i in 0..edits.len() {
+        if edits[i] == Edit::Equal {
+            continue;
+        }
+
+        let start: usize = i.saturating_sub(CONTEXT_LINES);
+        let end: usize = std::cmp::min(i + 1 + CONTEXT_LINES, edits.len());
+
+        if let Some(last) = hunks.last_mut()
+            && last.1 >= start
+        {
+            last.1 = end;
+            continue;
+        }
+
+        hunks.push((start, end));
+    }
+
+    return hunks;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Get the shortest edit script using the Myers diff algorithm.
+fn get_edits(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    let max: usize = old.len() + new.len();
+    let offset: isize = max as isize;
+    let mut v: Vec<isize> = vec![0; 2 * max + 2];
+    // only the diagonals -d..=d of each step are needed to walk back
+    let mut trace: Vec<Vec<isize>> = vec![];
+
+    'search: for d in 0..=max as isize {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let idx: usize = (k + offset) as usize;
+            let mut x: isize = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y: isize = x - k;
+            while (x as usize) < old.len()
+                && (y as usize) < new.len()
+                && old[x as usize] == new[y as usize]
+            {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x as usize >= old.len() && y as usize >= new.len() {
+                break 'search;
+            }
+        }
+    }
+
+    // walk the trace backwards to build the edit script
+    let mut edits: Vec<Edit> = vec![];
+    let mut x: isize = old.len() as isize;
+    let mut y: isize = new.len() as isize;
+    for d in (0..trace.len() as isize).rev() {
+        let v: &Vec<isize> = &trace[d as usize];
+        let k: isize = x - y;
+        let prev_k: isize =
+            if k == -d || (k != d && v[(k - 1 + d) as usize] < v[(k + 1 + d) as usize]) {
+                k + 1
+            } else {
+                k - 1
+            };
+        let prev_x: isize = if d == 0 { 0 } else { v[(prev_k + d) as usize] };
+        let prev_y: isize = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            edits.push(Edit::Equal);
+            x -= 1;
+            y -= 1;
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert);
+            } else {
+                edits.push(Edit::Delete);
+            }
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    edits.reverse();
+    return edits;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_unified_diff_equal() {
+        assert_eq!(get_unified_diff("A\nB\n", "A\nB\n", None), "");
+        assert_eq!(get_unified_diff("", "", Some(("a", "b"))), "");
+    }
+
+    #[test]
+    fn test_get_unified_diff_change() {
+        assert_eq!(
+            get_unified_diff("A\nB\nC\n", "A\nX\nC\n", None),
+            "@@ -1,3 +1,3 @@\n A\n-B\n+X\n C\n"
+        );
+    }
+
+    #[test]
+    fn test_get_unified_diff_headers() {
+        assert_eq!(
+            get_unified_diff("A\n", "B\n", Some(("a/in.sql", "b/in.sql"))),
+            "--- a/in.sql\n+++ b/in.sql\n@@ -1 +1 @@\n-A\n+B\n"
+        );
+    }
+
+    #[test]
+    fn test_get_unified_diff_insert_delete() {
+        assert_eq!(
+            get_unified_diff("A\nB\n", "A\nB\nC\n", None),
+            "@@ -1,2 +1,3 @@\n A\n B\n+C\n"
+        );
+        assert_eq!(
+            get_unified_diff("A\nB\nC\n", "B\nC\n", None),
+            "@@ -1,3 +1,2 @@\n-A\n B\n C\n"
+        );
+        assert_eq!(get_unified_diff("", "A\n", None), "@@ -0,0 +1 @@\n+A\n");
+    }
+
+    #[test]
+    fn test_get_unified_diff_no_newline_at_end() {
+        assert_eq!(
+            get_unified_diff("A\nB\n", "A\nB", None),
+            "@@ -1,2 +1,2 @@\n A\n-B\n+B\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_get_unified_diff_hunks() {
+        let old: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let new: &str = "1\nX\n3\n4\n5\n6\n7\n8\n9\n10\nY\n12\n";
+        assert_eq!(
+            get_unified_diff(old, new, None),
+            "@@ -1,5 +1,5 @@\n 1\n-2\n+X\n 3\n 4\n 5\n@@ -8,5 +8,5 @@\n 8\n 9\n 10\n-11\n+Y\n 12\n"
+        );
+
+        let new: &str = "1\nX\n3\n4\n5\n6\n7\nY\n9\n10\n11\n12\n";
+        assert_eq!(
+            get_unified_diff(old, new, None),
+            "@@ -1,11 +1,11 @@\n 1\n-2\n+X\n 3\n 4\n 5\n 6\n 7\n-8\n+Y\n 9\n 10\n 11\n"
+        );
+    }
+
+    #[test]
+    fn test_get_edits() {
+        let old: Vec<String> = (0..200).map(|i| (i % 7).to_string()).collect();
+        let new: Vec<String> = (0..150).map(|i| (i % 5).to_string()).collect();
+        let old: Vec<&str> = old.iter().map(|l| l.as_str()).collect();
+        let new: Vec<&str> = new.iter().map(|l| l.as_str()).collect();
+
+        // replaying the edits on old gives new
+        let mut result: Vec<&str> = vec![];
+        let (mut x, mut y): (usize, usize) = (0, 0);
+        for edit in get_edits(&old, &new) {
+            match edit {
+                Edit::Equal => {
+                    assert_eq!(old[x], new[y]);
+                    result.push(old[x]);
+                    x += 1;
+                    y += 1;
+                }
+                Edit::Delete => x += 1,
+                Edit::Insert => {
+                    result.push(new[y]);
+                    y += 1;
+                }
+            }
+        }
+        assert_eq!((x, y), (old.len(), new.len()));
+        assert_eq!(result, new);
+    }
+}
@@ -0,0 +1,323 @@
+#![allow(clippy::needless_return)]
+
+use std::{
+    fs::{DirEntry, FileType, ReadDir},
+    io,
+    path::{Path, PathBuf},
+};
+
+const SQL_EXTENSION: &str = "sql";
+
+pub fn get_sql_files(
+    paths: &[String],
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<PathBuf>, io::Error> {
+    let mut files: Vec<PathBuf> = vec![];
+
+    for path in paths {
+        let path: &Path = Path::new(path);
+        if path.is_dir() {
+            find_sql_files(path, path, include, exclude, &mut files)?;
+        } else if path.is_file() {
+            // explicitly provided files are always formatted
+            files.push(path.to_path_buf());
+        } else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Input path not found: {}", path.display()),
+            ));
+        }
+    }
+
+    return Ok(files);
+}
+
+fn find_sql_files(
+    root: &Path,
+    dir: &Path,
+    include: &[String],
+    exclude: &[String],
+    files: &mut Vec<PathBuf>,
+) -> Result<(), io::Error> {
+    let entries: ReadDir = dir.read_dir()?;
+    let mut entries: Vec<DirEntry> = entries.collect::<Result<Vec<DirEntry>, io::Error>>()?;
+    entries.sort_by_key(|e: &DirEntry| e.file_name());
+
+    for entry in entries {
+        let path: PathBuf = entry.path();
+        let relative_path: String = get_relative_path(root, &path);
+
+        if exclude.iter().any(|g| glob_match_path(g, &relative_path)) {
+            continue;
+        }
+
+        let file_type: FileType = entry.file_type()?;
+        if file_type.is_dir() {
+            find_sql_files(root, &path, include, exclude, files)?;
+            continue;
+        }
+
+        // symlinked directories are not followed, they could loop
+        if file_type.is_symlink() && path.is_dir() {
+            continue;
+        }
+
+        if !path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case(SQL_EXTENSION))
+        {
+            continue;
+        }
+
+        if !include.is_empty() && !include.iter().any(|g| glob_match_path(g, &relative_path)) {
+            continue;
+        }
+
+        files.push(path);
+    }
+
+    return Ok(());
+}
+
+pub fn get_relative_path(root: &Path, path: &Path) -> String {
+    let relative_path: &Path = path.strip_prefix(root).unwrap_or(path);
+    let mut parts: Vec<String> = vec![];
+    for component in relative_path.components() {
+        parts.push(component.as_os_str().to_string_lossy().to_string());
+    }
+    return parts.join("/");
+}
+
+/// Match a glob against a `/` separated path.
+///
+/// Globs without a `/` are matched against each path component,
+/// otherwise the glob is matched against the whole path.
+pub fn glob_match_path(glob: &str, path: &str) -> bool {
+    let glob: &str = glob.trim_start_matches("./");
+    if !glob.contains('/') {
+        return path.split('/').any(|c| glob_match(glob, c));
+    }
+    return glob_match(glob.trim_start_matches('/'), path)
+        || glob_match(&format!("{}/**", glob.trim_end_matches('/')), path);
+}
+
+/// Match a glob against a `/` separated path.
+///
+/// `*` matches within a path component, `**` matches across components and `?` matches one character.
+pub fn glob_match(glob: &str, path: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+    return glob_match_chars(&glob, &path);
+}
+
+fn glob_match_chars(glob: &[char], path: &[char]) -> bool {
+    if glob.is_empty() {
+        return path.is_empty();
+    }
+
+    match glob[0] {
+        '*' => {
+            if glob.get(1) == Some(&'*') {
+                // '**/' also matches zero directories
+                let rest: &[char] = if glob.get(2) == Some(&'/') {
+                    if glob_match_chars(&glob[3..], path) {
+                        return true;
+                    }
+                    &glob[2..]
+                } else {
+                    &glob[2..]
+                };
+                for i in 0..=path.len() {
+                    if glob_match_chars(rest, &path[i..]) {
+                        return true;
+                    }
+                }
+                return false;
+            }
+
+            for i in 0..=path.len() {
+                if glob_match_chars(&glob[1..], &path[i..]) {
+                    return true;
+                }
+                if path.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            return false;
+        }
+        '?' => {
+            if path.is_empty() || path[0] == '/' {
+                return false;
+            }
+            return glob_match_chars(&glob[1..], &path[1..]);
+        }
+        c => {
+            if path.first() != Some(&c) {
+                return false;
+            }
+            return glob_match_chars(&glob[1..], &path[1..]);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_glob_match_literal() {
+        assert_eq!(glob_match("a.sql", "a.sql"), true);
+        assert_eq!(glob_match("a.sql", "b.sql"), false);
+        assert_eq!(glob_match("a.sql", "a.sql2"), false);
+    }
+
+    #[test]
+    fn test_glob_match_star() {
+        assert_eq!(glob_match("*.sql", "a.sql"), true);
+        assert_eq!(glob_match("*.sql", ".sql"), true);
+        assert_eq!(glob_match("*.sql", "dir/a.sql"), false);
+        assert_eq!(glob_match("dir/*.sql", "dir/a.sql"), true);
+        assert_eq!(glob_match("dir/*", "dir/sub/a.sql"), false);
+    }
+
+    #[test]
+    fn test_glob_match_double_star() {
+        assert_eq!(glob_match("**/*.sql", "a.sql"), true);
+        assert_eq!(glob_match("**/*.sql", "dir/sub/a.sql"), true);
+        assert_eq!(glob_match("dir/**", "dir/sub/a.sql"), true);
+        assert_eq!(glob_match("dir/**/a.sql", "dir/a.sql"), true);
+        assert_eq!(glob_match("dir/**/a.sql", "dir/sub/a.sql"), true);
+        assert_eq!(glob_match("dir/**/a.sql", "other/sub/a.sql"), false);
+    }
+
+    #[test]
+    fn test_glob_match_question() {
+        assert_eq!(glob_match("v?.sql", "v1.sql"), true);
+        assert_eq!(glob_match("v?.sql", "v10.sql"), false);
+        assert_eq!(glob_match("dir?a.sql", "dir/a.sql"), false);
+    }
+
+    #[test]
+    fn test_glob_match_path_component() {
+        assert_eq!(
+            glob_match_path("*_generated.sql", "a/b_generated.sql"),
+            true
+        );
+        assert_eq!(glob_match_path("vendor", "vendor/a.sql"), true);
+        assert_eq!(glob_match_path("vendor", "src/vendor/a.sql"), true);
+        assert_eq!(glob_match_path("vendor", "src/a.sql"), false);
+    }
+
+    #[test]
+    fn test_glob_match_path_relative() {
+        assert_eq!(
+            glob_match_path("migrations/*.sql", "migrations/a.sql"),
+            true
+        );
+        assert_eq!(
+            glob_match_path("./migrations/*.sql", "migrations/a.sql"),
+            true
+        );
+        assert_eq!(glob_match_path("migrations/", "migrations/old/a.sql"), true);
+        assert_eq!(
+            glob_match_path("migrations/*.sql", "src/migrations/a.sql"),
+            false
+        );
+    }
+
+    #[test]
+    fn test_get_sql_files() {
+        let root: PathBuf =
+            std::env::temp_dir().join(format!("sqlfmt_test_get_sql_files_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("a/old")).unwrap();
+        fs::create_dir_all(root.join("b")).unwrap();
+        fs::write(root.join("a/1.sql"), "").unwrap();
+        fs::write(root.join("a/2.SQL"), "").unwrap();
+        fs::write(root.join("a/3.txt"), "").unwrap();
+        fs::write(root.join("a/old/4.sql"), "").unwrap();
+        fs::write(root.join("b/5.sql"), "").unwrap();
+        fs::write(root.join("b/6_generated.sql"), "").unwrap();
+
+        let paths: Vec<String> = vec![root.to_string_lossy().to_string()];
+
+        let files: Vec<PathBuf> = get_sql_files(&paths, &[], &[]).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                root.join("a/1.sql"),
+                root.join("a/2.SQL"),
+                root.join("a/old/4.sql"),
+                root.join("b/5.sql"),
+                root.join("b/6_generated.sql"),
+            ]
+        );
+
+        let files: Vec<PathBuf> = get_sql_files(
+            &paths,
+            &[],
+            &[String::from("old"), String::from("*_generated.sql")],
+        )
+        .unwrap();
+        assert_eq!(
+            files,
+            vec![
+                root.join("a/1.sql"),
+                root.join("a/2.SQL"),
+                root.join("b/5.sql"),
+            ]
+        );
+
+        let files: Vec<PathBuf> = get_sql_files(&paths, &[String::from("b/**")], &[]).unwrap();
+        assert_eq!(
+            files,
+            vec![root.join("b/5.sql"), root.join("b/6_generated.sql")]
+        );
+
+        let files: Vec<PathBuf> = get_sql_files(
+            &[root.join("a/3.txt").to_string_lossy().to_string()],
+            &[],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(files, vec![root.join("a/3.txt")]);
+
+        assert_eq!(
+            get_sql_files(&[root.join("c").to_string_lossy().to_string()], &[], &[]).is_err(),
+            true
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_sql_files_symlink_loop() {
+        let root: PathBuf = std::env::temp_dir().join(format!(
+            "sqlfmt_test_get_sql_files_symlink_loop_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("d")).unwrap();
+        fs::write(root.join("d/a.sql"), "").unwrap();
+        fs::write(root.join("b.sql"), "").unwrap();
+        std::os::unix::fs::symlink("..", root.join("d/loop")).unwrap();
+        std::os::unix::fs::symlink("../b.sql", root.join("d/link.sql")).unwrap();
+
+        let paths: Vec<String> = vec![root.to_string_lossy().to_string()];
+        let files: Vec<PathBuf> = get_sql_files(&paths, &[], &[]).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                root.join("b.sql"),
+                root.join("d/a.sql"),
+                root.join("d/link.sql"),
+            ]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
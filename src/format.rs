@@ -1,17 +1,41 @@
+#![allow(clippy::needless_return)]
+
 use std::collections::HashMap;
+use std::ops::Range;
 
-use crate::configuration::{ConfigCase, ConfigTab, Configuration};
+use crate::configuration::{
+    ConfigCase, ConfigCommaStyle, ConfigCommentStyle, ConfigLayout, ConfigLineEndings, ConfigTab,
+    Configuration,
+};
+use crate::diagnostic::{Diagnostic, DiagnosticKind};
 use crate::token::*;
 
 pub fn get_formatted_sql(config: &Configuration, input_sql: String) -> String {
+    return get_formatted_sql_and_diagnostics(config, input_sql).0;
+}
+
+/// Get formatted SQL, with diagnostics for malformed input.
+pub fn get_formatted_sql_and_diagnostics(
+    config: &Configuration,
+    input_sql: String,
+) -> (String, Vec<Diagnostic>) {
     let mut state: FormatState = FormatState::new();
 
-    let input_tokens: Vec<Token> = get_sql_tokens(config, input_sql);
+    let line_endings: ConfigLineEndings = get_line_endings(config, &input_sql);
+    let (mut input_tokens, mut diagnostics): (Vec<Token>, Vec<Diagnostic>) =
+        get_sql_tokens_and_diagnostics(config, input_sql.clone());
+    if config.comma_style == ConfigCommaStyle::Leading {
+        move_commas_after_comments(&mut input_tokens);
+    }
     for i in 0..input_tokens.len() {
         let input_token: &Token = &input_tokens[i];
         let prev_input_token: Option<&Token> = if i > 0 { input_tokens.get(i - 1) } else { None };
         let next_input_token: Option<&Token> = input_tokens.get(i + 1);
 
+        state.check_parens(input_token);
+        if state.continue_on_verbatim(input_token) {
+            continue;
+        }
         if state.continue_on_input_whitespace(
             input_token,
             prev_input_token,
@@ -25,12 +49,210 @@ pub fn get_formatted_sql(config: &Configuration, input_sql: String) -> String {
         state.add_pre_space(input_token, prev_input_token, config);
         state.set_previous_comment_pre_space(config);
         state.push(input_token.clone());
+        state.dedent_block_comment(config);
         state.increase_indent_stack(input_token);
         state.decrease_paren_stack(input_token);
         state.align_text_groups(config, input_token);
     }
 
-    return state.get_result(config);
+    if config.newlines {
+        state.collapse_short_lines(config);
+    }
+    if config.layout == ConfigLayout::River {
+        state.apply_river_layout(config);
+    }
+    state.align_clause_columns(config);
+    state.format_comments(config);
+    if config.wrap_comments {
+        state.wrap_comments(config);
+    }
+
+    diagnostics.append(&mut state.get_paren_diagnostics());
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+
+    return (
+        state.get_result(config, &line_endings, &input_sql),
+        diagnostics,
+    );
+}
+
+/// Get line endings to use, detecting the ones found the most in the input if auto.
+pub fn get_line_endings(config: &Configuration, input_sql: &str) -> ConfigLineEndings {
+    if config.line_endings != ConfigLineEndings::Auto {
+        return config.line_endings.clone();
+    }
+
+    let crlf_count: usize = input_sql.matches("\r\n").count();
+    let lf_count: usize = input_sql.matches(NEW_LINE).count() - crlf_count;
+    if crlf_count > lf_count {
+        return ConfigLineEndings::Crlf;
+    }
+    return ConfigLineEndings::Lf;
+}
+
+/// Move commas followed by comments to the start of the next line,
+/// keeping the comments on the line of the previous item.
+fn move_commas_after_comments(tokens: &mut Vec<Token>) {
+    let mut i: usize = 0;
+    while i < tokens.len() {
+        if tokens[i].category == Some(TokenCategory::Comma) {
+            let mut end: usize = i + 1;
+            loop {
+                let mut j: usize = end;
+                if tokens
+                    .get(j)
+                    .is_some_and(|t| t.category == Some(TokenCategory::WhiteSpace))
+                {
+                    j += 1;
+                }
+                if tokens
+                    .get(j)
+                    .is_some_and(|t| t.category == Some(TokenCategory::Comment))
+                    && tokens
+                        .get(j + 1)
+                        .is_some_and(|t| t.category == Some(TokenCategory::NewLine))
+                {
+                    end = j + 2;
+                } else {
+                    break;
+                }
+            }
+
+            if end > i + 1 {
+                let comma: Token = tokens.remove(i);
+                tokens.insert(end - 1, comma);
+                i = end - 1;
+            }
+        }
+        i += 1;
+    }
+}
+
+/// Range of input SQL to format.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FormatRange {
+    /// Inclusive 1-based line numbers.
+    Lines(usize, usize),
+    /// Byte offsets, end exclusive.
+    Bytes(usize, usize),
+}
+
+impl FormatRange {
+    /// Parse a `<START>:<END>` range.
+    pub fn parse(value: &str, lines: bool) -> Option<FormatRange> {
+        let (start, end) = value.split_once(':')?;
+        let start: usize = start.trim().parse::<usize>().ok()?;
+        let end: usize = end.trim().parse::<usize>().ok()?;
+        if start > end {
+            return None;
+        }
+        if lines {
+            if start == 0 {
+                return None;
+            }
+            return Some(FormatRange::Lines(start, end));
+        }
+        return Some(FormatRange::Bytes(start, end));
+    }
+
+    fn get_byte_range(&self, sql: &str) -> (usize, usize) {
+        match self {
+            FormatRange::Lines(start_line, end_line) => {
+                let mut line_starts: Vec<usize> = vec![0];
+                line_starts.extend(sql.match_indices(NEW_LINE).map(|(i, _)| i + 1));
+                let start: usize = *line_starts.get(start_line - 1).unwrap_or(&sql.len());
+                let end: usize = *line_starts.get(*end_line).unwrap_or(&sql.len());
+                return (start, end);
+            }
+            FormatRange::Bytes(start, end) => {
+                return (
+                    std::cmp::min(*start, sql.len()),
+                    std::cmp::min(*end, sql.len()),
+                );
+            }
+        }
+    }
+}
+
+/// Get SQL with only the statements overlapping the range formatted.
+///
+/// SQL outside of these statements is kept as is.
+pub fn get_formatted_sql_range(
+    config: &Configuration,
+    input_sql: &str,
+    range: &FormatRange,
+) -> String {
+    let (range_start, range_end): (usize, usize) = range.get_byte_range(input_sql);
+
+    let mut region: Option<(usize, usize)> = None;
+    for (statement_start, statement_end) in get_statement_ranges(config, input_sql) {
+        let overlaps: bool = if range_start == range_end {
+            statement_start <= range_start && range_start <= statement_end
+        } else {
+            statement_start < range_end && range_start < statement_end
+        };
+        if !overlaps {
+            continue;
+        }
+        region = match region {
+            Some((start, _)) => Some((start, statement_end)),
+            None => Some((statement_start, statement_end)),
+        };
+    }
+
+    let Some((mut start, end)) = region else {
+        return input_sql.to_string();
+    };
+
+    // detect line endings of the whole input, not only of the formatted statements
+    let mut config: Configuration = config.clone();
+    config.line_endings = get_line_endings(&config, input_sql);
+
+    // include line indentation to be detected as prefix
+    let line_start: usize = input_sql[..start].rfind(NEW_LINE).map_or(0, |i| i + 1);
+    if input_sql[line_start..start].trim().is_empty() {
+        start = line_start;
+    }
+
+    return format!(
+        "{}{}{}",
+        &input_sql[..start],
+        get_formatted_sql(&config, input_sql[start..end].to_string()),
+        &input_sql[end..]
+    );
+}
+
+/// Get byte ranges of each statement, from the first non-whitespace token to the delimiter.
+fn get_statement_ranges(config: &Configuration, input_sql: &str) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    let mut statement_start: Option<usize> = None;
+    let mut statement_end: usize = 0;
+
+    for token in get_sql_tokens(config, input_sql.to_string()) {
+        let Some(position) = token.position else {
+            continue;
+        };
+        match token.category {
+            Some(TokenCategory::WhiteSpace) | Some(TokenCategory::NewLine) => continue,
+            Some(TokenCategory::Delimiter) => {
+                ranges.push((statement_start.unwrap_or(position.start), position.end));
+                statement_start = None;
+                continue;
+            }
+            _ => (),
+        }
+
+        if statement_start.is_none() {
+            statement_start = Some(position.start);
+        }
+        statement_end = position.end;
+    }
+
+    if let Some(start) = statement_start {
+        ranges.push((start, statement_end));
+    }
+
+    return ranges;
 }
 
 struct FormatState {
@@ -38,6 +260,8 @@ struct FormatState {
     prefix: Option<String>,
     indent_stack: Vec<Token>,
     paren_stack: Vec<ParenCategory>,
+    paren_positions: Vec<(usize, usize)>,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl FormatState {
@@ -47,6 +271,8 @@ impl FormatState {
             prefix: None,
             indent_stack: vec![],
             paren_stack: vec![],
+            paren_positions: vec![],
+            diagnostics: vec![],
         }
     }
 
@@ -54,6 +280,32 @@ impl FormatState {
         self.tokens.push(token);
     }
 
+    fn continue_on_verbatim(&mut self, input_token: &Token) -> bool {
+        if input_token.category != Some(TokenCategory::Verbatim) {
+            return false;
+        }
+
+        while self
+            .tokens
+            .last()
+            .is_some_and(|t| t.category == Some(TokenCategory::WhiteSpace))
+        {
+            self.tokens.pop();
+        }
+
+        // keep trailing newline as token for following pre-space
+        match input_token.value.strip_suffix(NEW_LINE) {
+            Some(value) => {
+                let mut token: Token = input_token.clone();
+                token.value = value.to_string();
+                self.push(token);
+                self.push(Token::new_newline());
+            }
+            None => self.push(input_token.clone()),
+        }
+        return true;
+    }
+
     fn continue_on_input_whitespace(
         &mut self,
         input_token: &Token,
@@ -151,12 +403,46 @@ impl FormatState {
         self.paren_stack.push(ParenCategory::Space1Newline1);
     }
 
+    #[allow(clippy::collapsible_match)]
+    fn check_parens(&mut self, input_token: &Token) {
+        let Some(position) = input_token.position else {
+            return;
+        };
+        let start: (usize, usize) = (position.line, position.column);
+        match input_token.category {
+            Some(TokenCategory::ParenOpen) => self.paren_positions.push(start),
+            Some(TokenCategory::ParenClose) => {
+                if self.paren_positions.pop().is_none() {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::UnmatchedParen,
+                        start,
+                        (position.end_line, position.end_column),
+                    ));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn get_paren_diagnostics(&mut self) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = std::mem::take(&mut self.diagnostics);
+        for position in &self.paren_positions {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::UnclosedParen,
+                *position,
+                (position.0, position.1 + 1),
+            ));
+        }
+        return diagnostics;
+    }
+
     fn decrease_paren_stack(&mut self, token: &Token) {
         if token.category == Some(TokenCategory::ParenClose) {
             self.paren_stack.pop();
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn align_text_groups(&mut self, config: &Configuration, token: &Token) {
         if !config.align_text_groups {
             return;
@@ -226,8 +512,8 @@ impl FormatState {
                     Some(TokenCategory::ParenClose) => paren_count += 1,
                     Some(TokenCategory::Comma) => {
                         if paren_count == 1 {
-                            if current_group.is_some() {
-                                current_line.push(current_group.unwrap());
+                            if let Some(group) = current_group {
+                                current_line.push(group);
                             }
                             current_line.push(TextGroup {
                                 value: prev_token.value.clone(),
@@ -307,10 +593,7 @@ impl FormatState {
         }
 
         // find max len in each group
-        let mut max_len_in_each_group: Vec<usize> = vec![];
-        for _ in 0..max_groups_per_line {
-            max_len_in_each_group.push(0);
-        }
+        let mut max_len_in_each_group: Vec<usize> = vec![0; max_groups_per_line];
         for line in &lines {
             for group_idx in 0..line.len() {
                 if line[group_idx].len > max_len_in_each_group[group_idx] {
@@ -334,6 +617,7 @@ impl FormatState {
         }
     }
 
+    #[allow(clippy::collapsible_if, clippy::collapsible_match)]
     fn add_pre_space(
         &mut self,
         token: &Token,
@@ -430,7 +714,19 @@ impl FormatState {
     }
 
     fn insert_newline(&mut self, token: &Token, config: &Configuration) {
-        if self.get_current_line_length(token) > config.chars.into() {
+        let has_comment: bool = token.category == Some(TokenCategory::Comment)
+            || self
+                .tokens
+                .iter()
+                .rev()
+                .take_while(|t| t.category != Some(TokenCategory::NewLine))
+                .any(|t| t.category == Some(TokenCategory::Comment));
+        let chars: u16 = if has_comment {
+            config.get_comment_chars()
+        } else {
+            config.chars
+        };
+        if self.get_current_line_length(token) > chars.into() {
             self.insert_newline_after_last_operator(self.tokens.len(), config);
         }
     }
@@ -447,6 +743,7 @@ impl FormatState {
         return line_length;
     }
 
+    #[allow(clippy::collapsible_if)]
     fn add_pre_newline(&mut self, token: &Token) {
         if self.tokens.is_empty() {
             return;
@@ -470,6 +767,13 @@ impl FormatState {
             .expect("should always have a previous token");
         let prev3_token: Option<&Token> = self.tokens.iter().nth_back(2);
 
+        // keep token on the line of a leading comma
+        if prev1_token.category == Some(TokenCategory::Comma)
+            && prev1_token.behavior.contains(&TokenBehavior::NewLineBefore)
+        {
+            return;
+        }
+
         if token
             .behavior
             .contains(&TokenBehavior::NoNewLineBeforeUnlessMatch)
@@ -564,6 +868,7 @@ impl FormatState {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn remove_extra_newline(&mut self, token: &Token, config: &Configuration) {
         // collapse paren if short enough
         if token.category == Some(TokenCategory::ParenClose) {
@@ -613,6 +918,7 @@ impl FormatState {
                                 && pnwt.category != Some(TokenCategory::ParenOpen)
                                 && let Some(nnwt) = self.get_next_nonwhitespace_token(i)
                                 && nnwt.category != Some(TokenCategory::ParenClose)
+                                && nnwt.category != Some(TokenCategory::Comma)
                             {
                                 positions_to_add_space.push(i);
 
@@ -630,14 +936,18 @@ impl FormatState {
                 collapsed_line_len += prev_token.len();
             }
 
-            // determine whether to collapse paren
-            if inner_token_count <= 1 // paren is empty or just one inner token
-                || collapsed_line_len <= config.chars.into() // collapsed line is short enough
+            // determine whether collapsed paren fits on line
+            let collapsed_line_fits: bool = collapsed_line_len <= config.chars.into() // collapsed line is short enough
                 || last_operator_len_after.is_some_and(|len_after| {
                     collapsed_line_len - len_after <= config.chars.into() // line up to operator is short enough
                         && collapsed_inner_len + self.get_newline_pre_space_len(config) // collapsed paren plus indent is short enough
                             <= config.chars.into()
-                })
+                });
+
+            // determine whether to collapse paren
+            if inner_token_count <= 1 // paren is empty or just one inner token
+                || (collapsed_line_fits // collapsed paren is short enough
+                    && collapsed_inner_len <= config.get_paren_chars().into())
             {
                 for p in positions_to_remove {
                     self.tokens.remove(p);
@@ -833,6 +1143,7 @@ impl FormatState {
         return result;
     }
 
+    #[allow(clippy::collapsible_if)]
     fn increase_indent_stack(&mut self, token: &Token) {
         if token.behavior.contains(&TokenBehavior::IncreaseIndent) {
             self.indent_stack.push(token.clone());
@@ -904,7 +1215,7 @@ impl FormatState {
                     return;
                 }
 
-                if required_to_decrease.get(top_value.as_str()).is_some() {
+                if required_to_decrease.contains_key(top_value.as_str()) {
                     self.indent_stack.push(top);
                     return;
                 }
@@ -919,10 +1230,7 @@ impl FormatState {
                 }
                 let top: Token = top.unwrap();
 
-                if required_to_decrease
-                    .get(top.value.to_uppercase().as_str())
-                    .is_some()
-                {
+                if required_to_decrease.contains_key(top.value.to_uppercase().as_str()) {
                     self.indent_stack.push(top);
                     return;
                 }
@@ -975,260 +1283,2029 @@ impl FormatState {
         self.set_previous_line_comment_pre_space(newline_index - 4, pre_space);
     }
 
-    fn get_result(&self, config: &Configuration) -> String {
-        let mut result: String = String::new();
-        for i in 0..self.tokens.len() {
-            let token: &Token = &self.tokens[i];
-
-            let mut token_value: String = token.value.clone();
-
-            match token.category {
-                Some(TokenCategory::Keyword)
-                | Some(TokenCategory::DataType)
-                | Some(TokenCategory::Event)
-                | Some(TokenCategory::Method) => match config.case {
-                    ConfigCase::Uppercase => token_value = token_value.to_uppercase(),
-                    ConfigCase::Lowercase => token_value = token_value.to_lowercase(),
-                    ConfigCase::Unchanged => (),
-                },
-                Some(TokenCategory::XmlMethod) => {
-                    if result.ends_with(FULL_STOP)
-                        && self
-                            .tokens
-                            .get(i + 1)
-                            .is_some_and(|t| t.category == Some(TokenCategory::ParenOpen))
-                    {
-                        token_value = token_value.to_lowercase();
+    /// Join lines of statements, and of WHERE and ORDER BY clauses, short enough for one line.
+    fn collapse_short_lines(&mut self, config: &Configuration) {
+        if config.collapse_short_statements {
+            let mut starts: Vec<usize> = vec![];
+            let mut in_statement: bool = false;
+            for (i, token) in self.tokens.iter().enumerate() {
+                match token.category {
+                    Some(TokenCategory::WhiteSpace) | Some(TokenCategory::NewLine) => (),
+                    Some(TokenCategory::Delimiter) => in_statement = false,
+                    _ if !in_statement => {
+                        starts.push(i);
+                        in_statement = true;
                     }
+                    _ => (),
                 }
-                Some(TokenCategory::NewLine) => {
-                    result = result
-                        .trim_end_matches(|c: char| c.is_whitespace() && c != NEW_LINE)
-                        .to_string();
+            }
+
+            // collapse from the end, keeping positions of previous statements
+            for start in starts.into_iter().rev() {
+                let mut end: usize = start;
+                for i in start..self.tokens.len() {
+                    match self.tokens[i].category {
+                        Some(TokenCategory::WhiteSpace) | Some(TokenCategory::NewLine) => (),
+                        Some(TokenCategory::Delimiter) => {
+                            end = i + 1;
+                            break;
+                        }
+                        _ => end = i + 1,
+                    }
                 }
-                _ => (),
+                self.collapse_lines(start, end, config);
             }
+        }
 
-            result.push_str(token_value.as_str());
+        if config.collapse_short_clauses {
+            // collapse from the end, keeping positions of outer clauses
+            for start in (0..self.tokens.len()).rev() {
+                let token: &Token = &self.tokens[start];
+                if token.category != Some(TokenCategory::Keyword) {
+                    continue;
+                }
+                let is_clause: bool = match token.value.to_uppercase().as_str() {
+                    "WHERE" => true,
+                    "ORDER" => self
+                        .get_next_nonwhitespace_token(start)
+                        .is_some_and(|t| t.value.to_uppercase() == "BY"),
+                    _ => false,
+                };
+                if is_clause && let Some(end) = self.get_clause_end(start) {
+                    self.collapse_lines(start, end, config);
+                }
+            }
         }
-        return result
-            .trim_end_matches(|c: char| c.is_whitespace() && c != NEW_LINE)
-            .to_string();
     }
-}
 
-#[derive(PartialEq, Eq, Debug)]
-enum ParenCategory {
-    Space0Newline0,
-    Space0Newline1,
-    Space1Newline1,
-}
+    /// Get end of clause starting a line at `start`, before the first line not indented further.
+    fn get_clause_end(&self, start: usize) -> Option<usize> {
+        let line_start: usize = (0..start)
+            .rev()
+            .find(|i| self.tokens[*i].category == Some(TokenCategory::NewLine))
+            .map_or(0, |i| i + 1);
+        if self.tokens[line_start..start]
+            .iter()
+            .any(|t| t.category != Some(TokenCategory::WhiteSpace))
+        {
+            return None;
+        }
+        let indent_len: usize = self.tokens[line_start..start].iter().map(|t| t.len()).sum();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut end: usize = self.tokens.len();
+        for i in start..self.tokens.len() {
+            if self.tokens[i].category != Some(TokenCategory::NewLine) {
+                continue;
+            }
+            let mut line_indent_len: usize = 0;
+            let mut is_blank: bool = true;
+            for next_token in &self.tokens[i + 1..] {
+                match next_token.category {
+                    Some(TokenCategory::WhiteSpace) => line_indent_len += next_token.len(),
+                    Some(TokenCategory::NewLine) => break,
+                    _ => {
+                        is_blank = false;
+                        break;
+                    }
+                }
+            }
+            if is_blank || line_indent_len <= indent_len {
+                end = i;
+                break;
+            }
+        }
+        return Some(end);
+    }
+
+    /// Join lines of tokens in range if the joined line fits in `chars`.
+    fn collapse_lines(&mut self, start: usize, end: usize, config: &Configuration) {
+        let range: &[Token] = &self.tokens[start..end];
+        if !range
+            .iter()
+            .any(|t| t.category == Some(TokenCategory::NewLine))
+            || range.iter().any(|t| {
+                t.category == Some(TokenCategory::Comment)
+                    || t.category == Some(TokenCategory::Verbatim)
+            })
+        {
+            return;
+        }
 
-    #[test]
-    fn test_get_formatted_sql_empty() {
-        let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(r#""#);
+        // line before and after range stays as is
+        let line_start: usize = (0..start)
+            .rev()
+            .find(|i| self.tokens[*i].category == Some(TokenCategory::NewLine))
+            .map_or(0, |i| i + 1);
+        let line_end: usize = (end..self.tokens.len())
+            .find(|i| self.tokens[*i].category == Some(TokenCategory::NewLine))
+            .unwrap_or(self.tokens.len());
+        let mut collapsed_line_len: usize = self.tokens[line_start..start]
+            .iter()
+            .chain(&self.tokens[end..line_end])
+            .map(|t| t.len())
+            .sum();
+
+        // replace whitespace around newlines with a single space (or nothing next to parens and commas)
+        let mut tokens: Vec<Token> = vec![];
+        let mut i: usize = start;
+        while i < end {
+            let token: &Token = &self.tokens[i];
+            if token.category != Some(TokenCategory::WhiteSpace)
+                && token.category != Some(TokenCategory::NewLine)
+            {
+                collapsed_line_len += token.len();
+                tokens.push(token.clone());
+                i += 1;
+                continue;
+            }
 
-        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+            let mut j: usize = i;
+            while j < end
+                && (self.tokens[j].category == Some(TokenCategory::WhiteSpace)
+                    || self.tokens[j].category == Some(TokenCategory::NewLine))
+            {
+                j += 1;
+            }
+            if !self.tokens[i..j]
+                .iter()
+                .any(|t| t.category == Some(TokenCategory::NewLine))
+            {
+                collapsed_line_len += self.tokens[i..j].iter().map(|t| t.len()).sum::<usize>();
+                tokens.extend_from_slice(&self.tokens[i..j]);
+            } else if tokens
+                .last()
+                .is_some_and(|t| t.category != Some(TokenCategory::ParenOpen))
+                && self.tokens.get(j).is_some_and(|t| {
+                    t.category != Some(TokenCategory::ParenClose)
+                        && t.category != Some(TokenCategory::Comma)
+                })
+            {
+                collapsed_line_len += 1;
+                tokens.push(Token::new_whitespace(String::from(" ")));
+            }
+            i = j;
+        }
 
-        config.newlines = true;
-        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+        if collapsed_line_len <= config.chars.into() {
+            self.tokens.splice(start..end, tokens);
+        }
     }
 
-    #[test]
-    fn test_get_formatted_sql_whitespace_only() {
-        let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(
-            r#"
-
+    /// Align aliases, assignments and CASE branches starting on separate lines of each clause.
+    fn align_clause_columns(&mut self, config: &Configuration) {
+        // align from the end, inner clause padding moves outer clause columns on the same line
+        for start in (0..self.tokens.len()).rev() {
+            let token: &Token = &self.tokens[start];
+            if token.category != Some(TokenCategory::Keyword) {
+                continue;
+            }
+            let targets: Vec<usize> = match token.value.to_uppercase().as_str() {
+                "SELECT" if config.align_aliases => {
+                    self.get_clause_targets(start, &SELECT_LIST_END, "AS")
+                }
+                "SET" if config.align_assignments => {
+                    self.get_clause_targets(start, &SET_CLAUSE_END, "=")
+                }
+                "CASE" if config.align_case_then => self.get_case_targets(start),
+                _ => continue,
+            };
+            self.align_targets(&targets);
+        }
+    }
 
-        "#,
-        );
+    /// Get first `target` token of each comma separated item in clause.
+    fn get_clause_targets(&self, start: usize, end_keywords: &[&str], target: &str) -> Vec<usize> {
+        let mut targets: Vec<usize> = vec![];
+        let mut paren_depth: usize = 0;
+        let mut item_has_target: bool = false;
+        for i in start + 1..self.tokens.len() {
+            let token: &Token = &self.tokens[i];
+            match token.category {
+                Some(TokenCategory::ParenOpen) => paren_depth += 1,
+                Some(TokenCategory::ParenClose) => {
+                    if paren_depth == 0 {
+                        break;
+                    }
+                    paren_depth -= 1;
+                }
+                _ if paren_depth > 0 => (),
+                Some(TokenCategory::Delimiter) => break,
+                Some(TokenCategory::Keyword)
+                    if end_keywords.contains(&token.value.to_uppercase().as_str()) =>
+                {
+                    break;
+                }
+                Some(TokenCategory::Comma) => item_has_target = false,
+                _ if !item_has_target && token.value.to_uppercase() == target => {
+                    targets.push(i);
+                    item_has_target = true;
+                }
+                _ => (),
+            }
+        }
+        return targets;
+    }
 
-        assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"
+    /// Get THEN tokens of CASE branches, skipping nested CASE expressions.
+    fn get_case_targets(&self, start: usize) -> Vec<usize> {
+        let mut targets: Vec<usize> = vec![];
+        let mut paren_depth: usize = 0;
+        let mut case_depth: usize = 1;
+        for i in start + 1..self.tokens.len() {
+            let token: &Token = &self.tokens[i];
+            match token.category {
+                Some(TokenCategory::ParenOpen) => paren_depth += 1,
+                Some(TokenCategory::ParenClose) => {
+                    if paren_depth == 0 {
+                        break;
+                    }
+                    paren_depth -= 1;
+                }
+                Some(TokenCategory::Keyword) => match token.value.to_uppercase().as_str() {
+                    "CASE" => case_depth += 1,
+                    "END" => {
+                        case_depth -= 1;
+                        if case_depth == 0 {
+                            break;
+                        }
+                    }
+                    "THEN" if case_depth == 1 && paren_depth == 0 => targets.push(i),
+                    _ => (),
+                },
+                _ => (),
+            }
+        }
+        return targets;
+    }
+
+    /// Pad whitespace before targets to align them, if each target is on its own line.
+    fn align_targets(&mut self, targets: &[usize]) {
+        let mut columns: Vec<(usize, usize)> = vec![];
+        let mut line_starts: Vec<usize> = vec![];
+        for target in targets {
+            let line_start: usize = (0..*target)
+                .rev()
+                .find(|i| self.tokens[*i].category == Some(TokenCategory::NewLine))
+                .map_or(0, |i| i + 1);
+            if line_starts.contains(&line_start) {
+                return;
+            }
+            line_starts.push(line_start);
 
+            // only pad whitespace after other tokens of the line
+            let is_first: bool = self.tokens[line_start..*target]
+                .iter()
+                .all(|t| t.category == Some(TokenCategory::WhiteSpace));
+            if is_first || self.tokens[target - 1].category != Some(TokenCategory::WhiteSpace) {
+                continue;
+            }
 
-"#,
-        );
+            let mut column: usize = 0;
+            for token in &self.tokens[line_start..*target] {
+                match token.value.rfind(NEW_LINE) {
+                    Some(i) => column = token.value[i + 1..].len(),
+                    None => column += token.len(),
+                }
+            }
+            columns.push((*target, column));
+        }
 
-        config.newlines = true;
-        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+        if columns.len() < 2 {
+            return;
+        }
+        let max_column: usize = columns.iter().map(|(_, c)| *c).max().unwrap_or(0);
+        for (target, column) in columns {
+            let whitespace: &mut Token = &mut self.tokens[target - 1];
+            whitespace.value = " ".repeat(whitespace.len() + max_column - column);
+        }
     }
 
-    #[test]
-    fn test_get_formatted_sql_select_simple() {
-        let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(r#"SELECT * FROM TBL1"#);
-
-        config.newlines = false;
-        assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"SELECT * FROM TBL1"#
-        );
-    }
+    /// Right-align clause keywords of each statement so their bodies start at a common column.
+    ///
+    /// Applied on formatted lines, using the indent of each line as its depth.
+    fn apply_river_layout(&mut self, config: &Configuration) {
+        let unit: String = match config.tabs {
+            ConfigTab::Tab => String::from("\t"),
+            ConfigTab::Space(c) => " ".repeat(c as usize),
+        };
+        let prefix: String = self.prefix.clone().unwrap_or_default();
+
+        let mut lines: Vec<RiverLine> = vec![];
+        let mut start: usize = 0;
+        for i in 0..=self.tokens.len() {
+            if i < self.tokens.len() && self.tokens[i].category != Some(TokenCategory::NewLine) {
+                continue;
+            }
+            lines.push(self.get_river_line(start, i, &prefix, &unit, config));
+            start = i + 1;
+        }
 
-    #[test]
-    fn test_get_formatted_sql_newlines() {
-        let mut config: Configuration = Configuration::new();
+        let mut indents: Vec<Option<String>> = vec![None; lines.len()];
+        let mut merges: Vec<bool> = vec![false; lines.len()];
+        let mut statement: Vec<usize> = vec![];
+        for i in 0..lines.len() {
+            if lines[i].first.is_some() {
+                statement.push(i);
+            }
+            let ends_statement: bool = self.tokens[lines[i].start..lines[i].end]
+                .iter()
+                .rev()
+                .find(|t| t.category != Some(TokenCategory::WhiteSpace))
+                .is_some_and(|t| t.category == Some(TokenCategory::Delimiter));
+            if (ends_statement || i == lines.len() - 1) && !statement.is_empty() {
+                let base: usize = lines[statement[0]].depth;
+                self.set_river_indents(
+                    &lines,
+                    &statement,
+                    base,
+                    "",
+                    &unit,
+                    &mut indents,
+                    &mut merges,
+                );
+                statement = vec![];
+            }
+        }
+
+        let mut tokens: Vec<Token> = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            let merged: bool = i > 0 && merges[i - 1];
+            match (line.first, &indents[i]) {
+                (Some(first), _) if merged => {
+                    tokens.push(Token::new_whitespace(String::from(" ")));
+                    tokens.extend_from_slice(&self.tokens[first..line.end]);
+                }
+                (Some(first), Some(indent)) => {
+                    tokens.push(Token::new_whitespace(format!("{prefix}{indent}")));
+                    tokens.extend_from_slice(&self.tokens[first..line.end]);
+                }
+                _ => tokens.extend_from_slice(&self.tokens[line.start..line.end]),
+            }
+            if !merges[i] && line.end < self.tokens.len() {
+                tokens.push(self.tokens[line.end].clone());
+            }
+        }
+        self.tokens = tokens;
+    }
+
+    fn get_river_line(
+        &self,
+        start: usize,
+        end: usize,
+        prefix: &str,
+        unit: &str,
+        config: &Configuration,
+    ) -> RiverLine {
+        let mut line: RiverLine = RiverLine {
+            start,
+            end,
+            first: None,
+            depth: 0,
+        };
+
+        let Some(first) =
+            (start..end).find(|i| self.tokens[*i].category != Some(TokenCategory::WhiteSpace))
+        else {
+            return line;
+        };
+
+        // keep verbatim and input indented comment lines as is
+        let first_token: &Token = &self.tokens[first];
+        if first_token.category == Some(TokenCategory::Verbatim)
+            || (first_token.category == Some(TokenCategory::Comment) && !config.comment_pre_space)
+        {
+            return line;
+        }
+
+        let pre_space: String = self.tokens[start..first]
+            .iter()
+            .map(|t| t.value.as_str())
+            .collect();
+        let Some(indent) = pre_space.strip_prefix(prefix) else {
+            return line;
+        };
+
+        line.first = Some(first);
+        line.depth = if unit.is_empty() {
+            0
+        } else {
+            indent.matches(unit).count()
+        };
+        return line;
+    }
+
+    /// Set indents of statement (or nested block) lines, recursing into nested statements.
+    #[allow(clippy::too_many_arguments)]
+    fn set_river_indents(
+        &self,
+        lines: &[RiverLine],
+        block: &[usize],
+        base: usize,
+        base_indent: &str,
+        unit: &str,
+        indents: &mut [Option<String>],
+        merges: &mut [bool],
+    ) {
+        let is_keyword_line = |line: &RiverLine| -> bool {
+            let Some(first) = line.first else {
+                return false;
+            };
+            let token: &Token = &self.tokens[first];
+            return (line.depth == base && token.category == Some(TokenCategory::Keyword))
+                || (line.depth == base + 1
+                    && RIVER_SUB_CLAUSES.contains(&token.value.to_uppercase().as_str()));
+        };
+
+        let width: usize = block
+            .iter()
+            .filter(|i| is_keyword_line(&lines[**i]))
+            .map(|i| self.tokens[lines[*i].first.unwrap()].len())
+            .max()
+            .unwrap_or(0);
+        if width == 0 {
+            return;
+        }
+        let body_indent: String = format!("{base_indent}{}", " ".repeat(width + 1));
+
+        let mut b: usize = 0;
+        while b < block.len() {
+            let i: usize = block[b];
+            let line: &RiverLine = &lines[i];
+            let first: &Token = &self.tokens[line.first.unwrap()];
+
+            if is_keyword_line(line) {
+                indents[i] = Some(format!("{base_indent}{}", " ".repeat(width - first.len())));
+
+                // keyword only line, continue with body on same line
+                if line.depth == base
+                    && self.tokens[line.first.unwrap()..line.end].iter().all(|t| {
+                        t.category == Some(TokenCategory::Keyword)
+                            || t.category == Some(TokenCategory::WhiteSpace)
+                    })
+                    && let Some(next) = block.get(b + 1)
+                    && *next == i + 1
+                    && lines[*next].depth == base + 1
+                    && !is_keyword_line(&lines[*next])
+                    && !RIVER_STATEMENTS.contains(
+                        &self.tokens[lines[*next].first.unwrap()]
+                            .value
+                            .to_uppercase()
+                            .as_str(),
+                    )
+                {
+                    merges[i] = true;
+                }
+            } else if line.depth == base {
+                indents[i] = Some(base_indent.to_string());
+            } else if line.depth == base + 1 && first.category == Some(TokenCategory::Comma) {
+                // leading comma in the river, before the body
+                indents[i] = Some(format!("{base_indent}{}", " ".repeat(width - 1)));
+            } else if line.depth > base {
+                let indent: String = format!("{body_indent}{}", unit.repeat(line.depth - base - 1));
+
+                if first.category == Some(TokenCategory::Keyword)
+                    && RIVER_STATEMENTS.contains(&first.value.to_uppercase().as_str())
+                {
+                    // nested statement, aligned on its own
+                    let end: usize = block[b..]
+                        .iter()
+                        .position(|n| lines[*n].depth < line.depth)
+                        .map_or(block.len(), |p| b + p);
+                    self.set_river_indents(
+                        lines,
+                        &block[b..end],
+                        line.depth,
+                        &indent,
+                        unit,
+                        indents,
+                        merges,
+                    );
+                    b = end;
+                    continue;
+                }
+
+                indents[i] = Some(indent);
+            }
+            b += 1;
+        }
+    }
+
+    /// Remove input indent of block comment inner lines, to be indented with the comment later.
+    fn dedent_block_comment(&mut self, config: &Configuration) {
+        if !config.indent_block_comments {
+            return;
+        }
+        let Some(token) = self.tokens.last_mut() else {
+            return;
+        };
+        let Some(column) = token.position.map(|p| p.column) else {
+            return;
+        };
+        if token.category != Some(TokenCategory::Comment) || !token.value.starts_with("/*") {
+            return;
+        }
+
+        token.value = token
+            .value
+            .split(NEW_LINE)
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    return line;
+                }
+                let len: usize = line
+                    .chars()
+                    .take(column - 1)
+                    .take_while(|c| c.is_whitespace())
+                    .map(|c| c.len_utf8())
+                    .sum();
+                &line[len..]
+            })
+            .collect::<Vec<&str>>()
+            .join("\n");
+    }
+
+    /// Convert comment style, re-indent block comments and add gutters to them.
+    fn format_comments(&mut self, config: &Configuration) {
+        if config.comment_style == ConfigCommentStyle::Preserve
+            && !config.indent_block_comments
+            && !config.block_comment_gutters
+        {
+            return;
+        }
+
+        // format from the end, keeping positions of previous comments
+        let mut i: usize = self.tokens.len();
+        while i > 0 {
+            i -= 1;
+            let token: &Token = &self.tokens[i];
+            if token.category != Some(TokenCategory::Comment) {
+                continue;
+            }
+
+            let line_start: usize = self.get_line_start(i);
+            let is_own_line: bool = self.tokens[line_start..i]
+                .iter()
+                .all(|t| t.category == Some(TokenCategory::WhiteSpace));
+            let indent: String = if is_own_line {
+                self.tokens[line_start..i]
+                    .iter()
+                    .map(|t| t.value.as_str())
+                    .collect()
+            } else {
+                " ".repeat(self.tokens[line_start..i].iter().map(|t| t.len()).sum())
+            };
+            let is_line_end: bool = self.is_line_end(i + 1);
+
+            // keep optimizer hints and unclosed comments as is
+            if token.value.starts_with("/*") {
+                if token.value.starts_with("/*+") || !token.value.ends_with("*/") {
+                    continue;
+                }
+
+                let lines: Vec<String> = get_block_comment_lines(&token.value);
+                if config.comment_style == ConfigCommentStyle::Line && is_line_end {
+                    let values: Vec<String> = lines
+                        .iter()
+                        .map(|l| format!("-- {l}").trim_end().to_string())
+                        .collect();
+                    self.replace_comment_lines(i..i + 1, &values, &indent);
+                } else if token.value.contains(NEW_LINE) {
+                    if config.block_comment_gutters {
+                        self.tokens[i].value = get_block_comment(&lines, &indent, true);
+                    } else if config.indent_block_comments {
+                        self.tokens[i].value = token
+                            .value
+                            .split(NEW_LINE)
+                            .enumerate()
+                            .map(|(l, line)| match l {
+                                0 => line.to_string(),
+                                _ if line.trim().is_empty() => String::new(),
+                                _ => format!("{indent}{line}"),
+                            })
+                            .collect::<Vec<String>>()
+                            .join("\n");
+                    }
+                }
+                continue;
+            }
+
+            // directives are only recognized as line comments
+            if config.comment_style != ConfigCommentStyle::Block
+                || !is_line_end
+                || get_directive_value(&token.value).is_some()
+            {
+                continue;
+            }
+            let mut positions: Vec<usize> = vec![i];
+            if is_own_line {
+                while let Some(prev) = self.get_prev_comment_line(positions[0], &indent) {
+                    positions.insert(0, prev);
+                }
+            }
+
+            let lines: Vec<String> = positions
+                .iter()
+                .map(|p| {
+                    let line: CommentLine =
+                        CommentLine::from_single_line(&self.tokens[*p].value, 0, "");
+                    line.text.trim_end().to_string()
+                })
+                .collect();
+            // the comment would close early, or never close when comments nest
+            let is_nested: bool = is_dialect_nested_comment(&config.dialect);
+            if !lines
+                .iter()
+                .any(|l| l.contains("*/") || (is_nested && l.contains("/*")))
+            {
+                let value: String =
+                    get_block_comment(&lines, &indent, config.block_comment_gutters);
+                self.replace_comment_lines(positions[0]..i + 1, &[value], &indent);
+            }
+            i = positions[0];
+        }
+    }
+
+    /// Replace tokens in range with comments on their own lines.
+    fn replace_comment_lines(&mut self, range: Range<usize>, values: &[String], indent: &str) {
+        let mut tokens: Vec<Token> = vec![];
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                tokens.push(Token::new_newline());
+                if !indent.is_empty() {
+                    tokens.push(Token::new_whitespace(indent.to_string()));
+                }
+            }
+            let mut comment: Token = self.tokens[range.start].clone();
+            comment.value = value.clone();
+            tokens.push(comment);
+        }
+        self.tokens.splice(range, tokens);
+    }
+
+    /// Wrap comment lines longer than `comment_chars`, as runs of `--` comment lines or block comments.
+    fn wrap_comments(&mut self, config: &Configuration) {
+        let width: usize = config.get_comment_chars().into();
+
+        // wrap from the end, keeping positions of previous comments
+        let mut i: usize = self.tokens.len();
+        while i > 0 {
+            i -= 1;
+            let token: &Token = &self.tokens[i];
+            if token.category != Some(TokenCategory::Comment) {
+                continue;
+            }
+
+            let line_start: usize = self.get_line_start(i);
+            let indent: String = self.tokens[line_start..i]
+                .iter()
+                .map(|t| t.value.as_str())
+                .collect();
+            let is_own_line: bool = self.tokens[line_start..i]
+                .iter()
+                .all(|t| t.category == Some(TokenCategory::WhiteSpace));
+            let column: usize = self.tokens[line_start..i].iter().map(|t| t.len()).sum();
+
+            if token.value.starts_with("/*") {
+                let indent: String = if is_own_line {
+                    indent
+                } else {
+                    " ".repeat(column)
+                };
+                if let Some(value) = get_wrapped_block_comment(&token.value, column, &indent, width)
+                {
+                    self.tokens[i].value = value;
+                }
+                continue;
+            }
+
+            // only wrap runs of comments on their own lines
+            if !is_own_line || !self.is_line_end(i + 1) {
+                continue;
+            }
+            let mut positions: Vec<usize> = vec![i];
+            while let Some(prev) = self.get_prev_comment_line(positions[0], &indent) {
+                positions.insert(0, prev);
+            }
+
+            let lines: Vec<CommentLine> = positions
+                .iter()
+                .map(|p| CommentLine::from_single_line(&self.tokens[*p].value, column, &indent))
+                .collect();
+            if let Some(lines) = get_wrapped_comment_lines(&lines, width) {
+                let values: Vec<String> = lines.iter().map(|l| l.get_value()).collect();
+                self.replace_comment_lines(positions[0]..i + 1, &values, &indent);
+            }
+            i = positions[0];
+        }
+    }
+
+    fn is_line_end(&self, pos: usize) -> bool {
+        return self.tokens[pos..]
+            .iter()
+            .take_while(|t| t.category != Some(TokenCategory::NewLine))
+            .all(|t| t.category == Some(TokenCategory::WhiteSpace));
+    }
+
+    fn get_line_start(&self, pos: usize) -> usize {
+        return (0..pos)
+            .rev()
+            .find(|i| self.tokens[*i].category == Some(TokenCategory::NewLine))
+            .map_or(0, |i| i + 1);
+    }
+
+    /// Get position of line comment on its own line, with the same indent, on the line before `pos`.
+    fn get_prev_comment_line(&self, pos: usize, indent: &str) -> Option<usize> {
+        let line_start: usize = self.get_line_start(pos);
+        let prev: usize = (0..line_start.saturating_sub(1))
+            .rev()
+            .find(|i| self.tokens[*i].category != Some(TokenCategory::WhiteSpace))?;
+        if self.tokens[prev].category != Some(TokenCategory::Comment) {
+            return None;
+        }
+
+        let prev_line_start: usize = self.get_line_start(prev);
+        let prev_indent: String = self.tokens[prev_line_start..prev]
+            .iter()
+            .map(|t| t.value.as_str())
+            .collect();
+        if self.tokens[prev].value.starts_with("/*")
+            || get_directive_value(&self.tokens[prev].value).is_some()
+            || prev_indent != indent
+            || self.tokens[prev_line_start..prev]
+                .iter()
+                .any(|t| t.category != Some(TokenCategory::WhiteSpace))
+        {
+            return None;
+        }
+        return Some(prev);
+    }
+
+    /// Get case to apply to the tokens matching `is_cased`, resolving consistent case from them.
+    fn get_resolved_case(&self, case: &ConfigCase, is_cased: fn(&Token) -> bool) -> ConfigCase {
+        if *case != ConfigCase::Consistent {
+            return case.clone();
+        }
+
+        let mut upper_count: usize = 0;
+        let mut lower_count: usize = 0;
+        for token in self.tokens.iter().filter(|t| is_cased(t)) {
+            if token.value == token.value.to_lowercase() {
+                lower_count += 1;
+            } else if token.value == token.value.to_uppercase() {
+                upper_count += 1;
+            }
+        }
+
+        // ties go to uppercase, the usual SQL style
+        if upper_count == 0 && lower_count == 0 {
+            return ConfigCase::Unchanged;
+        } else if lower_count > upper_count {
+            return ConfigCase::Lowercase;
+        }
+        return ConfigCase::Uppercase;
+    }
+
+    #[allow(clippy::collapsible_match)]
+    /// Get formatted SQL, with line endings between tokens and input ones in quotes and verbatim regions.
+    fn get_result(
+        &self,
+        config: &Configuration,
+        line_endings: &ConfigLineEndings,
+        input_sql: &str,
+    ) -> String {
+        let case: ConfigCase = self.get_resolved_case(&config.case, |t| {
+            t.category == Some(TokenCategory::Keyword) || t.category == Some(TokenCategory::Event)
+        });
+        let datatype_case: ConfigCase = self.get_resolved_case(config.get_datatype_case(), |t| {
+            t.category == Some(TokenCategory::DataType)
+        });
+        let function_case: ConfigCase = self.get_resolved_case(config.get_function_case(), |t| {
+            t.category == Some(TokenCategory::Method)
+        });
+        let identifier_case: ConfigCase =
+            self.get_resolved_case(&config.identifier_case, is_cased_identifier);
+
+        let mut result: String = String::new();
+        for i in 0..self.tokens.len() {
+            let token: &Token = &self.tokens[i];
+
+            let mut token_value: String = token.value.clone();
+
+            match token.category {
+                Some(TokenCategory::Keyword) | Some(TokenCategory::Event) => {
+                    token_value = get_cased_value(token_value, &case);
+                }
+                Some(TokenCategory::DataType) => {
+                    token_value = get_cased_value(token_value, &datatype_case);
+                }
+                Some(TokenCategory::Method) => {
+                    token_value = get_cased_value(token_value, &function_case);
+                }
+                None if is_cased_identifier(token) => {
+                    token_value = get_cased_value(token_value, &identifier_case);
+                }
+                Some(TokenCategory::XmlMethod) => {
+                    if result.ends_with(FULL_STOP)
+                        && self
+                            .tokens
+                            .get(i + 1)
+                            .is_some_and(|t| t.category == Some(TokenCategory::ParenOpen))
+                    {
+                        token_value = token_value.to_lowercase();
+                    }
+                }
+                Some(TokenCategory::NewLine) => {
+                    if i == 0 || self.tokens[i - 1].category != Some(TokenCategory::Verbatim) {
+                        result = result
+                            .trim_end_matches(|c: char| c.is_whitespace() && c != NEW_LINE)
+                            .to_string();
+                    }
+                }
+                _ => (),
+            }
+
+            match token.category {
+                Some(TokenCategory::Quote) | Some(TokenCategory::Verbatim) => {
+                    match get_input_line_endings(token, input_sql) {
+                        Some(value) => result.push_str(&value),
+                        None => result.push_str(&token_value),
+                    }
+                }
+                _ => result.push_str(&token_value.replace(NEW_LINE, line_endings.get_value())),
+            }
+        }
+        return result
+            .trim_end_matches(|c: char| c.is_whitespace() && c != NEW_LINE)
+            .to_string();
+    }
+}
+
+/// Get token value with the carriage returns of its input line endings, `None` if not from the input.
+fn get_input_line_endings(token: &Token, input_sql: &str) -> Option<String> {
+    let position: TokenPosition = token.position?;
+    let mut input_chars = input_sql.get(position.start..position.end)?.chars();
+    let mut value: String = String::new();
+    for c in token.value.chars() {
+        let mut input_c: char = input_chars.next()?;
+        while input_c == CARRIAGE_RETURN {
+            if c == NEW_LINE {
+                value.push(CARRIAGE_RETURN);
+            }
+            input_c = input_chars.next()?;
+        }
+        if input_c != c {
+            return None;
+        }
+        value.push(c);
+    }
+    return Some(value);
+}
+
+fn get_cased_value(value: String, case: &ConfigCase) -> String {
+    return match case {
+        ConfigCase::Uppercase => value.to_uppercase(),
+        ConfigCase::Lowercase => value.to_lowercase(),
+        ConfigCase::Unchanged | ConfigCase::Consistent => value,
+    };
+}
+
+/// Check if token is an unquoted identifier, skipping numbers, variables and temp tables.
+///
+/// Keywords of other dialects are left uncategorized, but are not identifiers either.
+fn is_cased_identifier(token: &Token) -> bool {
+    return token.category.is_none()
+        && token
+            .value
+            .starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && !is_known_value(&token.value);
+}
+
+/// Comment line split into its leading marker and text, for wrapping.
+#[derive(Clone)]
+struct CommentLine {
+    /// Column of the line, to measure its length.
+    column: usize,
+    /// Whitespace to start continuation lines with.
+    indent: String,
+    leader: String,
+    text: String,
+}
+
+impl CommentLine {
+    fn from_single_line(value: &str, column: usize, indent: &str) -> CommentLine {
+        let text: &str = value.trim_start_matches(['-', '#']);
+        let text: &str = text.strip_prefix(' ').unwrap_or(text);
+        return CommentLine {
+            column,
+            indent: indent.to_string(),
+            leader: value[..value.len() - text.len()].to_string(),
+            text: text.to_string(),
+        };
+    }
+
+    fn len(&self) -> usize {
+        return self.column + self.leader.len() + self.text.len();
+    }
+
+    fn get_value(&self) -> String {
+        return format!("{}{}", self.leader, self.text)
+            .trim_end()
+            .to_string();
+    }
+
+    /// Get line continuing this one when wrapped.
+    fn get_continuation(&self, text: String) -> CommentLine {
+        let leader: String = match self.leader.strip_prefix("/*") {
+            Some(rest) => format!("  {rest}"),
+            None => self.leader.clone(),
+        };
+        return CommentLine {
+            column: self
+                .indent
+                .chars()
+                .map(|c| if c == TAB { 4 } else { 1 })
+                .sum(),
+            indent: self.indent.clone(),
+            leader,
+            text,
+        };
+    }
+
+    fn is_fence(&self) -> bool {
+        return self.text.trim_start().starts_with("```");
+    }
+
+    /// Check if line is kept as is, being empty, a code sample or a directive.
+    fn is_kept(&self) -> bool {
+        return self.text.trim().is_empty()
+            || self.text.trim() == "*/"
+            || self.text.starts_with("    ")
+            || self.text.starts_with(TAB)
+            || self.text.starts_with("sqlfmt:")
+            || self.is_fence();
+    }
+
+    /// Get length of bullet list marker starting the text, like `- ` or `1. `.
+    fn get_bullet_len(&self) -> Option<usize> {
+        if ["- ", "* ", "+ "].iter().any(|b| self.text.starts_with(b)) {
+            return Some(2);
+        }
+        let digits: usize = self.text.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0
+            && (self.text[digits..].starts_with(". ") || self.text[digits..].starts_with(") "))
+        {
+            return Some(digits + 2);
+        }
+        return None;
+    }
+}
+
+/// Get text lines of block comment, without markers, gutters and common indent.
+fn get_block_comment_lines(value: &str) -> Vec<String> {
+    let body: &str = value
+        .strip_prefix("/*")
+        .and_then(|v| v.strip_suffix("*/"))
+        .unwrap_or(value);
+
+    let mut lines: Vec<&str> = vec![];
+    for (i, line) in body.split(NEW_LINE).enumerate() {
+        if i == 0 {
+            lines.push(line.trim());
+            continue;
+        }
+        // remove gutter, keeping the indent after it
+        let text: &str = line.trim_start();
+        lines.push(match text.strip_prefix('*') {
+            Some(rest) if rest.is_empty() || rest.starts_with(' ') => {
+                rest.strip_prefix(' ').unwrap_or(rest)
+            }
+            _ => line,
+        });
+    }
+
+    let common_len: usize = lines
+        .iter()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| match i {
+            0 => l.to_string(),
+            _ if l.trim().is_empty() => String::new(),
+            _ => l[common_len..].trim_end().to_string(),
+        })
+        .collect();
+
+    while lines.len() > 1 && lines[0].is_empty() {
+        lines.remove(0);
+    }
+    while lines.len() > 1 && lines[lines.len() - 1].is_empty() {
+        lines.pop();
+    }
+    return lines;
+}
+
+/// Get block comment of text lines, indenting inner lines with `indent`.
+fn get_block_comment(lines: &[String], indent: &str, gutters: bool) -> String {
+    if gutters && lines.len() > 1 {
+        let mut value: String = String::from("/*");
+        for line in lines {
+            value.push_str(format!("\n{indent} * {line}").trim_end());
+        }
+        value.push_str(&format!("\n{indent} */"));
+        return value;
+    }
+
+    // inner lines aligned with the text after `/* `
+    let mut value: String = String::from("/*");
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            value.push(NEW_LINE);
+            if !line.is_empty() {
+                value.push_str(&format!("{indent}  "));
+            }
+        }
+        if !line.is_empty() {
+            value.push_str(&format!(" {line}"));
+        }
+    }
+    value.push_str(" */");
+    return value;
+}
+
+/// Get block comment with lines wrapped to fit `width`, if any line is too long.
+fn get_wrapped_block_comment(
+    value: &str,
+    column: usize,
+    indent: &str,
+    width: usize,
+) -> Option<String> {
+    // keep optimizer hints as is
+    if value.starts_with("/*+") {
+        return None;
+    }
+
+    let mut lines: Vec<CommentLine> = vec![];
+    for (i, line) in value.split(NEW_LINE).enumerate() {
+        let (leader_len, line_column): (usize, usize) = if i == 0 {
+            let text: &str = line[2..].trim_start();
+            (line.len() - text.len(), column)
+        } else {
+            let text: &str = line.trim_start();
+            let gutter: &str = text
+                .strip_prefix('*')
+                .filter(|t| !t.starts_with('/'))
+                .map_or(text, |t| t.strip_prefix(' ').unwrap_or(t));
+            (line.len() - gutter.len(), 0)
+        };
+        lines.push(CommentLine {
+            column: line_column,
+            indent: if i == 0 {
+                indent.to_string()
+            } else {
+                String::new()
+            },
+            leader: line[..leader_len].to_string(),
+            text: line[leader_len..].to_string(),
+        });
+    }
+
+    let lines: Vec<CommentLine> = get_wrapped_comment_lines(&lines, width)?;
+    return Some(
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, l)| {
+                if i > 0 {
+                    format!("{}{}", l.indent, l.get_value())
+                } else {
+                    l.get_value()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+    );
+}
+
+/// Get comment lines with paragraphs wrapped to fit `width`, if any line is too long.
+///
+/// Empty lines, code samples and directives are kept, bullet list items are wrapped on their own.
+fn get_wrapped_comment_lines(lines: &[CommentLine], width: usize) -> Option<Vec<CommentLine>> {
+    let mut wrapped: Vec<CommentLine> = vec![];
+    let mut changed: bool = false;
+    let mut in_fence: bool = false;
+
+    let mut i: usize = 0;
+    while i < lines.len() {
+        let line: &CommentLine = &lines[i];
+        if in_fence || line.is_kept() {
+            in_fence ^= line.is_fence();
+            wrapped.push(line.clone());
+            i += 1;
+            continue;
+        }
+
+        // paragraph continues on lines with the same leader, indented past bullet if any
+        let bullet_len: usize = line.get_bullet_len().unwrap_or(0);
+        let bullet_indent: String = " ".repeat(bullet_len);
+        let mut end: usize = i + 1;
+        while end < lines.len() {
+            let next_line: &CommentLine = &lines[end];
+            let text: &str = next_line
+                .text
+                .strip_prefix(bullet_indent.as_str())
+                .unwrap_or("");
+            if next_line.leader != line.leader
+                || text.is_empty()
+                || text.starts_with(char::is_whitespace)
+                || next_line.is_kept()
+                || next_line.get_bullet_len().is_some()
+            {
+                break;
+            }
+            end += 1;
+        }
+
+        let paragraph: &[CommentLine] = &lines[i..end];
+        i = end;
+        if paragraph.iter().all(|l| l.len() <= width) {
+            wrapped.extend_from_slice(paragraph);
+            continue;
+        }
+
+        // refill words, keeping bullet marker on first line
+        let mut current: CommentLine = CommentLine {
+            text: line.text[..bullet_len].to_string(),
+            ..line.clone()
+        };
+        let mut current_has_word: bool = false;
+        for word in paragraph
+            .iter()
+            .flat_map(|l| l.text[bullet_len..].split_whitespace())
+        {
+            // keep block comment end with the last word
+            if current_has_word && word != "*/" && current.len() + 1 + word.len() > width {
+                let next: CommentLine = current.get_continuation(bullet_indent.clone());
+                wrapped.push(current);
+                current = next;
+                current_has_word = false;
+            }
+            if current_has_word {
+                current.text.push(' ');
+            }
+            current.text.push_str(word);
+            current_has_word = true;
+        }
+        wrapped.push(current);
+        changed = true;
+    }
+
+    if !changed {
+        return None;
+    }
+    return Some(wrapped);
+}
+
+/// Keywords right-aligned with clause keywords when starting a line of the clause body.
+const RIVER_SUB_CLAUSES: [&str; 11] = [
+    "AND", "CROSS", "FULL", "INNER", "JOIN", "LEFT", "NATURAL", "ON", "OR", "OUTER", "RIGHT",
+];
+
+/// Keywords ending a select list, for aligning aliases.
+const SELECT_LIST_END: [&str; 21] = [
+    "DELETE",
+    "EXCEPT",
+    "FETCH",
+    "FOR",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "INSERT",
+    "INTERSECT",
+    "INTO",
+    "LIMIT",
+    "MERGE",
+    "OFFSET",
+    "ORDER",
+    "QUALIFY",
+    "SELECT",
+    "UNION",
+    "UPDATE",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+];
+
+/// Keywords ending a SET clause, for aligning assignments.
+const SET_CLAUSE_END: [&str; 11] = [
+    "DELETE",
+    "FROM",
+    "INSERT",
+    "MERGE",
+    "OUTPUT",
+    "RETURNING",
+    "SELECT",
+    "UPDATE",
+    "WHEN",
+    "WHERE",
+    "WITH",
+];
+
+/// Keywords starting a nested statement, with its own river.
+const RIVER_STATEMENTS: [&str; 6] = ["DELETE", "INSERT", "MERGE", "SELECT", "UPDATE", "WITH"];
+
+/// Formatted line for river layout, `first` is the first non-whitespace token if indent is known.
+struct RiverLine {
+    start: usize,
+    end: usize,
+    first: Option<usize>,
+    depth: usize,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum ParenCategory {
+    Space0Newline0,
+    Space0Newline1,
+    Space1Newline1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::ConfigDialect;
+
+    #[test]
+    fn test_get_formatted_sql_empty() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(r#""#);
+
+        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+
+        config.newlines = true;
+        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+    }
+
+    #[test]
+    fn test_get_formatted_sql_whitespace_only() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+
+
+        "#,
+        );
+
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"
+
+
+"#,
+        );
+
+        config.newlines = true;
+        assert_eq!(get_formatted_sql(&config, sql.clone()), r#""#);
+    }
+
+    #[test]
+    fn test_get_formatted_sql_select_simple() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(r#"SELECT * FROM TBL1"#);
+
+        config.newlines = false;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"SELECT * FROM TBL1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_newlines() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(r#"SELECT * FROM TBL1"#);
+
+        config.newlines = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"SELECT
+    *
+FROM TBL1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_upper() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(r#"select * from tbl1"#);
+
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"SELECT * FROM tbl1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_lower() {
+        let mut config: Configuration = Configuration::new();
         let sql: String = String::from(r#"SELECT * FROM TBL1"#);
 
+        config.case = ConfigCase::Lowercase;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"select * from TBL1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_category_case() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"Select Count(Tbl1.C1), Cast(@Var As VarChar(10)), "Col A", [Col B], 1E5 From Tbl1"#,
+        );
+
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"SELECT COUNT(Tbl1.C1), CAST(@Var AS VARCHAR(10)), "Col A", [Col B], 1E5 FROM Tbl1"#
+        );
+
+        config.identifier_case = ConfigCase::Lowercase;
+        config.function_case = Some(ConfigCase::Lowercase);
+        config.datatype_case = Some(ConfigCase::Unchanged);
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"SELECT count(tbl1.c1), cast(@Var AS VarChar(10)), "Col A", [Col B], 1E5 FROM tbl1"#
+        );
+
+        config.dialect = ConfigDialect::Postgres;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("Select Tbl1.C1 From Tbl1 Qualify Go")),
+            "SELECT tbl1.c1 FROM tbl1 Qualify Go"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_consistent() {
+        let mut config: Configuration = Configuration::new();
+
+        config.case = ConfigCase::Consistent;
+        assert_eq!(
+            get_formatted_sql(
+                &config,
+                String::from("Select a FROM t where b = 1 AND c = 2")
+            ),
+            "SELECT a FROM t WHERE b = 1 AND c = 2"
+        );
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT Count(a) from t where b = 1")),
+            "select Count(a) from t where b = 1"
+        );
+        assert_eq!(
+            get_formatted_sql(&config, String::from("Select a From t")),
+            "Select a From t"
+        );
+
+        config.identifier_case = ConfigCase::Consistent;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("select COL1, COL2, col3 from TBL1")),
+            "select COL1, COL2, COL3 from TBL1"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_select_simple_newlines() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT  *
+            FROM  TBL1
+            "#,
+        );
+
+        config.newlines = false;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"
+            SELECT *
+            FROM TBL1
+"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_tabs() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT
+                C1,
+                C2
+            FROM TBL1
+            "#,
+        );
+
+        config.tabs = ConfigTab::Tab;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"
+            SELECT
+            	C1,
+            	C2
+            FROM TBL1
+"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_prefix_tabs() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+			SELECT
+				C1,
+				C2
+			FROM TBL1
+            "#,
+        );
+
+        config.newlines = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"			SELECT
+			    C1,
+			    C2
+			FROM TBL1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_spaces() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT
+                C1,
+                C2
+            FROM TBL1
+            "#,
+        );
+
+        config.tabs = ConfigTab::Space(2);
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"
+            SELECT
+              C1,
+              C2
+            FROM TBL1
+"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_chars() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT
+            (SELECT C1, C2 FROM TBL1)
+            "#,
+        );
+
+        config.newlines = true;
+
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                (SELECT C1, C2 FROM TBL1)"#
+        );
+
+        config.chars = 40;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                (
+                    SELECT
+                        C1,
+                        C2
+                    FROM TBL1
+                )"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_chars_wide() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = format!(
+            "SELECT {}\nFROM TBL1",
+            (1..=25)
+                .map(|i| format!("COLUMN{i}"))
+                .collect::<Vec<String>>()
+                .join(" + ")
+        );
+
+        config.newlines = true;
+        config.chars = 300;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone())
+                .lines()
+                .map(|l| l.len())
+                .collect::<Vec<usize>>(),
+            vec![6, 267, 9]
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_comment_chars() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT
+            COLUMN1 + COLUMN2 + COLUMN3 + COLUMN4 -- comment that is quite long
+            FROM TBL1
+            "#,
+        );
+
+        config.newlines = true;
+        config.chars = 60;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                COLUMN1 + COLUMN2 + COLUMN3 +
+                COLUMN4 -- comment that is quite long
+            FROM TBL1"#
+        );
+
+        config.comment_chars = Some(100);
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                COLUMN1 + COLUMN2 + COLUMN3 + COLUMN4 -- comment that is quite long
+            FROM TBL1"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_paren_chars() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT
+            (SELECT C1, C2 FROM TBL1), COALESCE(C1, C2)
+            "#,
+        );
+
+        config.newlines = true;
+        config.paren_chars = Some(20);
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                (
+                    SELECT
+                        C1,
+                        C2
+                    FROM TBL1
+                ),
+                COALESCE(C1, C2)"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_comma_style() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+            SELECT C1, COALESCE(C2, C3) AS C4, (SELECT C5, C6 FROM TBL2), CASE WHEN C7 THEN 1 END C8
+            FROM TBL1
+            GROUP BY C1, C4;
+            CREATE TABLE TBL1 (ID INT, NAME VARCHAR(10), DESCRIPTION VARCHAR(100), CREATED TIMESTAMP);
+            "#,
+        );
+
+        config.newlines = true;
+        config.comma_style = ConfigCommaStyle::Leading;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT
+                C1
+                , COALESCE(C2, C3) AS C4
+                , (SELECT C5, C6 FROM TBL2)
+                , CASE
+                    WHEN C7 THEN 1
+                END C8
+            FROM TBL1
+            GROUP BY C1
+                , C4;
+
+            CREATE TABLE TBL1(
+                ID INT
+                , NAME VARCHAR(10)
+                , DESCRIPTION VARCHAR(100)
+                , CREATED TIMESTAMP
+            );"#
+        );
+
+        config.newlines = false;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT C1, C2\n, C3 FROM TBL1")),
+            "SELECT C1, C2\n    , C3 FROM TBL1"
+        );
+
+        config.newlines = true;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT C1, -- first\nC2 FROM TBL1")),
+            "SELECT\n    C1 -- first\n    , C2\nFROM TBL1"
+        );
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT C1 -- first\n, C2 FROM TBL1")),
+            "SELECT\n    C1 -- first\n    , C2\nFROM TBL1"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_collapse_short_statements() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            "SELECT 1; SELECT id FROM t WHERE x = 1 AND y = 2;\nSELECT a, b -- note\nFROM t",
+        );
+
+        config.newlines = true;
+        config.collapse_short_statements = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT 1;\n\nSELECT id FROM t WHERE x = 1 AND y = 2;\n\nSELECT\n    a,\n    b -- note\nFROM t"
+        );
+
+        config.chars = 30;
+        assert_eq!(
+            get_formatted_sql(
+                &config,
+                String::from("SELECT id FROM t WHERE x = 1 AND y = 2")
+            ),
+            "SELECT\n    id\nFROM t\nWHERE x = 1\n    AND y = 2"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_collapse_short_clauses() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            "SELECT a, b FROM t WHERE a IN (SELECT b FROM u WHERE c = 1 AND d = 2) AND e = 3 ORDER BY a, b DESC",
+        );
+
+        config.newlines = true;
+        config.collapse_short_clauses = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT\n    a,\n    b\nFROM t\nWHERE a IN (SELECT b FROM u WHERE c = 1 AND d = 2) AND e = 3\nORDER BY a, b DESC"
+        );
+
+        config.chars = 50;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT\n    a,\n    b\nFROM t\nWHERE a IN (SELECT b FROM u WHERE c = 1 AND d = 2)\n    AND e = 3\nORDER BY a, b DESC"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_align_aliases() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            "SELECT a AS x, bbbb AS yy, ccc, CAST(d AS INT) AS d FROM (SELECT e AS f, ggg AS h FROM t) AS s",
+        );
+
+        config.newlines = true;
+        config.align_aliases = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT\n    a              AS x,\n    bbbb           AS yy,\n    ccc,\n    CAST(d AS INT) AS d\nFROM (SELECT e AS f, ggg AS h FROM t) AS s"
+        );
+
+        config.newlines = false;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT a AS x,\n  bb AS yy\nFROM t")),
+            "SELECT a AS x,\n    bb   AS yy\nFROM t"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_align_assignments() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from("UPDATE t SET a = 1, bbbb = 2, cc = b = 3 WHERE d = 3");
+
+        config.newlines = true;
+        config.align_assignments = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "UPDATE t\nSET a    = 1,\n    bbbb = 2,\n    cc   = b = 3\nWHERE d = 3"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_config_align_case_then() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            "SELECT CASE WHEN a = 1 THEN 'one' WHEN a = 22 THEN (CASE WHEN b THEN 1 ELSE 2 END) END FROM t",
+        );
+
         config.newlines = true;
+        config.align_case_then = true;
         assert_eq!(
             get_formatted_sql(&config, sql.clone()),
             r#"SELECT
-    *
-FROM TBL1"#
+    CASE
+        WHEN a = 1  THEN 'one'
+        WHEN a = 22 THEN (CASE WHEN b THEN 1 ELSE 2 END)
+    END
+FROM t"#
         );
     }
 
     #[test]
-    fn test_get_formatted_sql_upper() {
+    fn test_get_formatted_sql_config_wrap_comments() {
         let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(r#"select * from tbl1"#);
+        let sql: String = String::from(
+            r#"
+-- This comment explains what the following query does, in more words than fit.
+-- It continues here.
+--
+-- - a bullet item that is also quite long and should wrap under the item text
+-- - short item
+--     code sample kept exactly as it is, no matter how long the line of it gets
+-- sqlfmt: on
+SELECT a, /* inline block comment that is long and goes past the width */ b
+FROM t /* comment that ends right at the width of the line */
+/*
+ * Block comment line with gutter that is long enough to be wrapped at the width.
+ */
+WHERE x = 1"#,
+        );
 
-        config.case = ConfigCase::Uppercase;
+        config.wrap_comments = true;
+        config.chars = 60;
         assert_eq!(
             get_formatted_sql(&config, sql.clone()),
-            r#"SELECT * FROM tbl1"#
+            r#"
+-- This comment explains what the following query does, in
+-- more words than fit. It continues here.
+--
+-- - a bullet item that is also quite long and should wrap
+--   under the item text
+-- - short item
+--     code sample kept exactly as it is, no matter how long the line of it gets
+-- sqlfmt: on
+SELECT a, /* inline block comment that is long and goes past
+             the width */ b
+FROM t /* comment that ends right at the width of the line */
+/*
+ * Block comment line with gutter that is long enough to be
+ * wrapped at the width.
+ */
+WHERE x = 1"#
         );
+
+        config.chars = 100;
+        assert_eq!(get_formatted_sql(&config, sql.clone()), sql);
     }
 
     #[test]
-    fn test_get_formatted_sql_lower() {
+    fn test_get_formatted_sql_config_block_comments() {
         let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(r#"SELECT * FROM TBL1"#);
+        let sql: String = String::from(
+            r#"
+BEGIN
+        /*
+            Procedure body comment,
+              with indented detail.
+        */
+        SELECT 1;
+END"#,
+        );
 
-        config.case = ConfigCase::Lowercase;
+        config.newlines = true;
+        config.comment_pre_space = true;
+        config.indent_block_comments = true;
         assert_eq!(
             get_formatted_sql(&config, sql.clone()),
-            r#"select * from TBL1"#
+            r#"BEGIN
+    /*
+        Procedure body comment,
+          with indented detail.
+    */
+    SELECT
+        1;
+END"#
+        );
+
+        config.block_comment_gutters = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"BEGIN
+    /*
+     * Procedure body comment,
+     *   with indented detail.
+     */
+    SELECT
+        1;
+END"#
         );
     }
 
     #[test]
-    fn test_get_formatted_sql_select_simple_newlines() {
+    fn test_get_formatted_sql_config_comment_style() {
         let mut config: Configuration = Configuration::new();
         let sql: String = String::from(
             r#"
-            SELECT  *
-            FROM  TBL1
-            "#,
+-- first line
+-- second line
+SELECT a /* inline */ + 1, /* trailing
+   block */
+    b -- trailing
+FROM t /*+ hint */"#,
         );
 
-        config.newlines = false;
+        config.comment_style = ConfigCommentStyle::Block;
         assert_eq!(
             get_formatted_sql(&config, sql.clone()),
             r#"
-            SELECT *
-            FROM TBL1
-"#
+/* first line
+   second line */
+SELECT a /* inline */ + 1, /* trailing
+   block */
+    b /* trailing */
+FROM t /*+ hint */"#
+        );
+
+        config.comment_style = ConfigCommentStyle::Line;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"
+-- first line
+-- second line
+SELECT a /* inline */ + 1, -- trailing
+                           -- block
+    b -- trailing
+FROM t /*+ hint */"#
+        );
+
+        config.comment_style = ConfigCommentStyle::Block;
+        let sql: String = String::from("SELECT a -- see /* the docs\nFROM t");
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT a /* see /* the docs */\nFROM t"
         );
+
+        config.dialect = ConfigDialect::Postgres;
+        assert_eq!(get_formatted_sql(&config, sql.clone()), sql);
     }
 
     #[test]
-    fn test_get_formatted_sql_config_tabs() {
+    fn test_get_formatted_sql_config_comment_style_directives() {
         let mut config: Configuration = Configuration::new();
+        config.comment_style = ConfigCommentStyle::Block;
         let sql: String = String::from(
-            r#"
-            SELECT
-                C1,
-                C2
-            FROM TBL1
-            "#,
+            "-- note\n-- sqlfmt: off\nselect  1;\n-- sqlfmt: on\n-- after\nselect  2;",
         );
 
-        config.tabs = ConfigTab::Tab;
+        let formatted: String = get_formatted_sql(&config, sql);
         assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"
-            SELECT
-            	C1,
-            	C2
-            FROM TBL1
-"#
+            formatted,
+            "/* note */\n-- sqlfmt: off\nselect  1;\n-- sqlfmt: on\n/* after */\nselect 2;"
+        );
+
+        // the region still ends when formatting again
+        assert_eq!(
+            get_formatted_sql(&config, format!("{formatted}\nselect   3;")),
+            "/* note */\n-- sqlfmt: off\nselect  1;\n-- sqlfmt: on\n/* after */\nselect 2;\nselect 3;"
         );
     }
 
     #[test]
-    fn test_get_formatted_sql_prefix_tabs() {
+    fn test_get_formatted_sql_config_line_endings() {
         let mut config: Configuration = Configuration::new();
-        let sql: String = String::from(
-            r#"
-			SELECT
-				C1,
-				C2
-			FROM TBL1
-            "#,
+        config.newlines = true;
+
+        let sql: &str = "select a /* b\r\nc */ from t;\r\nselect 1\n";
+        assert_eq!(
+            get_formatted_sql(&config, sql.to_string()),
+            "select\r\n    a /* b\r\nc */\r\nfrom t;\r\n\r\nselect\r\n    1"
+        );
+
+        let sql: &str = "select a from t;\r\nselect 1\n";
+        assert_eq!(
+            get_formatted_sql(&config, sql.to_string()),
+            "select\n    a\nfrom t;\n\nselect\n    1"
         );
 
+        config.line_endings = ConfigLineEndings::Crlf;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("select a from t")),
+            "select\r\n    a\r\nfrom t"
+        );
+
+        config.newlines = false;
+        config.line_endings = ConfigLineEndings::Lf;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("select a\r\nfrom t\r\n")),
+            "select a\nfrom t\n"
+        );
+
+        // quotes and verbatim regions keep their input line endings
         config.newlines = true;
+        config.line_endings = ConfigLineEndings::Auto;
+        let sql: &str = "select 'a\nb', $$c\nd$$;\r\n-- sqlfmt: off\r\nselect  2 ,\n 3;\r\n-- sqlfmt: on\r\nselect 4\r\n";
         assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"			SELECT
-			    C1,
-			    C2
-			FROM TBL1"#
+            get_formatted_sql(&config, sql.to_string()),
+            "select\r\n    'a\nb',\r\n    $$c\nd$$;\r\n\r\n-- sqlfmt: off\r\nselect  2 ,\n 3;\r\n-- sqlfmt: on\r\nselect\r\n    4"
+        );
+
+        config.line_endings = ConfigLineEndings::Lf;
+        assert_eq!(
+            get_formatted_sql(&config, sql.to_string()),
+            "select\n    'a\nb',\n    $$c\nd$$;\n\n-- sqlfmt: off\r\nselect  2 ,\n 3;\n-- sqlfmt: on\nselect\n    4"
         );
     }
 
     #[test]
-    fn test_get_formatted_sql_config_spaces() {
+    fn test_get_formatted_sql_config_layout_river() {
         let mut config: Configuration = Configuration::new();
         let sql: String = String::from(
             r#"
-            SELECT
-                C1,
-                C2
-            FROM TBL1
+            SELECT C1, C2 FROM TBL1 INNER JOIN TBL2 ON TBL1.ID = TBL2.ID
+            WHERE C1 = 1 AND C2 = 2 GROUP BY C1, C2 HAVING COUNT(*) > 1 ORDER BY C1;
+            UPDATE TBL1 SET C1 = 1, C2 = 2 WHERE C3 = 3;
             "#,
         );
 
-        config.tabs = ConfigTab::Space(2);
+        config.newlines = true;
+        config.layout = ConfigLayout::River;
         assert_eq!(
             get_formatted_sql(&config, sql.clone()),
-            r#"
-            SELECT
-              C1,
-              C2
-            FROM TBL1
-"#
+            r#"            SELECT C1,
+                   C2
+              FROM TBL1
+             INNER JOIN TBL2 ON TBL1.ID = TBL2.ID
+             WHERE C1 = 1
+               AND C2 = 2
+             GROUP BY C1,
+                   C2
+            HAVING COUNT(*) > 1
+             ORDER BY C1;
+
+            UPDATE TBL1
+               SET C1 = 1,
+                   C2 = 2
+             WHERE C3 = 3;"#
+        );
+
+        config.comma_style = ConfigCommaStyle::Leading;
+        assert_eq!(
+            get_formatted_sql(&config, String::from("SELECT C1, C2 FROM TBL1")),
+            "SELECT C1\n     , C2\n  FROM TBL1"
         );
     }
 
     #[test]
-    fn test_get_formatted_sql_config_chars() {
+    fn test_get_formatted_sql_config_layout_river_nested() {
         let mut config: Configuration = Configuration::new();
         let sql: String = String::from(
             r#"
-            SELECT
-            (SELECT C1, C2 FROM TBL1)
+            SELECT C1 FROM (SELECT COLUMN1 AS C1, COLUMN2, COLUMN3 FROM TBL1 WHERE COLUMN1 > 1 AND COLUMN2 < 5) AS T1
+            -- comment
+            WHERE C1 IN (SELECT C2 FROM TBL2);
             "#,
         );
 
         config.newlines = true;
-
-        assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"            SELECT
-                (SELECT C1, C2 FROM TBL1)"#
+        config.layout = ConfigLayout::River;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT C1
+              FROM (
+                       SELECT COLUMN1 AS C1,
+                              COLUMN2,
+                              COLUMN3
+                         FROM TBL1
+                        WHERE COLUMN1 > 1
+                          AND COLUMN2 < 5
+                   ) AS T1
+            -- comment
+             WHERE C1 IN (SELECT C2 FROM TBL2);"#
         );
 
-        config.chars = 40;
+        config.newlines = false;
         assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
-            r#"            SELECT
-                (
-                    SELECT
-                        C1,
-                        C2
-                    FROM TBL1
-                )"#
+            get_formatted_sql(
+                &config,
+                String::from("SELECT C1,\nC2\nFROM TBL1\nWHERE C1 = 1")
+            ),
+            "SELECT C1,\n       C2\n  FROM TBL1\n WHERE C1 = 1"
         );
     }
 
@@ -2485,11 +4562,11 @@ SET C3 = 3"#
                     'VALUE 6'
                 );
 "#
+            .replace('\n', "\r\n")
         );
 
         config.newlines = true;
-        assert_eq!(
-            get_formatted_sql(&config, sql.clone()),
+        let expected: String = String::from(
             r#"            SELECT
                 *
             FROM TBL1
@@ -2500,8 +4577,15 @@ SET C3 = 3"#
                     'VALUE 4',
                     'VALUE 5',
                     'VALUE 6'
-                );"#
+                );"#,
+        );
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            expected.replace('\n', "\r\n")
         );
+
+        config.line_endings = ConfigLineEndings::Lf;
+        assert_eq!(get_formatted_sql(&config, sql.clone()), expected);
     }
 
     #[test]
@@ -3143,6 +5227,44 @@ FROM TBL1;"#
         );
     }
 
+    #[test]
+    fn test_get_formatted_sql_optimizer_hints() {
+        let mut config: Configuration = Configuration::new();
+        config.newlines = true;
+        let sql: String = String::from(
+            r#"
+            SELECT
+            /*+ INDEX(TBL1 IDX1) */ C1, C2 FROM TBL1;
+            DELETE /*+ NO_MERGE */ FROM TBL1 WHERE C1 = 1
+            "#,
+        );
+
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT /*+ INDEX(TBL1 IDX1) */
+                C1,
+                C2
+            FROM TBL1;
+
+            DELETE /*+ NO_MERGE */
+            FROM TBL1
+            WHERE C1 = 1"#
+        );
+
+        config.comment_pre_space = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"            SELECT /*+ INDEX(TBL1 IDX1) */
+                C1,
+                C2
+            FROM TBL1;
+
+            DELETE /*+ NO_MERGE */
+            FROM TBL1
+            WHERE C1 = 1"#
+        );
+    }
+
     #[test]
     fn test_get_formatted_sql_select_into() {
         let mut config: Configuration = Configuration::new();
@@ -4895,4 +7017,164 @@ CALL SP1()"#
                 @LogTable;"#
         );
     }
+
+    #[test]
+    fn test_get_formatted_sql_dollar_quote() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"
+CREATE FUNCTION add_one(i integer) RETURNS integer AS $$
+    BEGIN
+        RETURN i  +  1;
+    END;
+$$ LANGUAGE plpgsql;
+            "#,
+        );
+
+        config.newlines = true;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"CREATE FUNCTION add_one(i integer)
+RETURNS integer AS $$
+    BEGIN
+        RETURN i  +  1;
+    END;
+$$ LANGUAGE plpgsql;"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_and_diagnostics_parens() {
+        let config: Configuration = Configuration::new();
+        assert_eq!(
+            get_formatted_sql_and_diagnostics(&config, String::from("SELECT (1, (2)")),
+            (
+                String::from("SELECT (1, (2)"),
+                vec![Diagnostic::new(
+                    DiagnosticKind::UnclosedParen,
+                    (1, 8),
+                    (1, 9)
+                )]
+            )
+        );
+        assert_eq!(
+            get_formatted_sql_and_diagnostics(&config, String::from("SELECT 1)\nFROM ')' (t)")).1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnmatchedParen,
+                (1, 9),
+                (1, 10)
+            )]
+        );
+        assert_eq!(
+            get_formatted_sql_and_diagnostics(&config, String::from("SELECT (1 /* ( */)")).1,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_directive_off() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"select a,b from t;
+-- sqlfmt: off
+INSERT INTO t VALUES
+    (1,   'a'),   
+    (22,  'bb');
+-- sqlfmt: on
+select   c from   u;"#,
+        );
+
+        config.newlines = true;
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT\n    a,\n    b\nFROM t;\n\n-- sqlfmt: off\nINSERT INTO t VALUES\n    (1,   'a'),   \n    (22,  'bb');\n-- sqlfmt: on\nSELECT\n    c\nFROM u;"
+        );
+
+        config.newlines = false;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            "SELECT a, b FROM t;\n-- sqlfmt: off\nINSERT INTO t VALUES\n    (1,   'a'),   \n    (22,  'bb');\n-- sqlfmt: on\nSELECT c FROM u;"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_directive_skip_next() {
+        let mut config: Configuration = Configuration::new();
+        let sql: String = String::from(
+            r#"-- sqlfmt: skip-next
+select   x,
+  y   from z;
+select  d from e;"#,
+        );
+
+        config.newlines = true;
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql(&config, sql.clone()),
+            r#"-- sqlfmt: skip-next
+select   x,
+  y   from z;
+
+SELECT
+    d
+FROM e;"#
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_range_lines() {
+        let mut config: Configuration = Configuration::new();
+        let sql: &str = "select  a from t;\n\n    select   b,c from u;\n    select  d from e;\n";
+
+        config.newlines = true;
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Lines(3, 3)),
+            "select  a from t;\n\n    SELECT\n        b,\n        c\n    FROM u;\n    select  d from e;\n"
+        );
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Lines(2, 2)),
+            sql
+        );
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Lines(1, 9)),
+            "SELECT\n    a\nFROM t;\n\nSELECT\n    b,\n    c\nFROM u;\n\nSELECT\n    d\nFROM e;\n"
+        );
+    }
+
+    #[test]
+    fn test_get_formatted_sql_range_bytes() {
+        let mut config: Configuration = Configuration::new();
+        let sql: &str = "select  a from t; select  b from u;\r\nselect  c";
+
+        config.case = ConfigCase::Uppercase;
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Bytes(20, 20)),
+            "select  a from t; SELECT b FROM u;\r\nselect  c"
+        );
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Bytes(17, 18)),
+            sql
+        );
+        assert_eq!(
+            get_formatted_sql_range(&config, sql, &FormatRange::Bytes(38, 99)),
+            "select  a from t; select  b from u;\r\nSELECT c"
+        );
+    }
+
+    #[test]
+    fn test_format_range_parse() {
+        assert_eq!(
+            FormatRange::parse("2:5", true),
+            Some(FormatRange::Lines(2, 5))
+        );
+        assert_eq!(FormatRange::parse("0:5", true), None);
+        assert_eq!(
+            FormatRange::parse("0:5", false),
+            Some(FormatRange::Bytes(0, 5))
+        );
+        assert_eq!(FormatRange::parse("5:2", false), None);
+        assert_eq!(FormatRange::parse("5", false), None);
+    }
 }
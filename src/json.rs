@@ -0,0 +1,373 @@
+#![allow(clippy::needless_return)]
+
+use std::fmt;
+
+/// Minimal JSON value, enough for JSON-RPC messages.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(value: &str) -> Result<Json, String> {
+        let mut parser: Parser = Parser {
+            chars: value.chars().collect(),
+            position: 0,
+        };
+        let json: Json = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.position < parser.chars.len() {
+            return Err(format!("Unexpected character at {}", parser.position));
+        }
+        return Ok(json);
+    }
+
+    pub fn object(entries: Vec<(&str, Json)>) -> Json {
+        return Json::Object(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+        );
+    }
+
+    /// Get value of key if an object.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        if let Json::Object(entries) = self {
+            return entries.iter().find(|(k, _)| k == key).map(|(_, v)| v);
+        }
+        return None;
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        if let Json::String(value) = self {
+            return Some(value);
+        }
+        return None;
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        if let Json::Bool(value) = self {
+            return Some(*value);
+        }
+        return None;
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        if let Json::Number(value) = self
+            && *value >= 0.0
+            && value.fract() == 0.0
+        {
+            return Some(*value as usize);
+        }
+        return None;
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Json>> {
+        if let Json::Array(values) = self {
+            return Some(values);
+        }
+        return None;
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{value}"),
+            Json::Number(value) => {
+                if value.fract() == 0.0 && value.abs() < 1e15 {
+                    write!(f, "{}", *value as i64)
+                } else {
+                    write!(f, "{value}")
+                }
+            }
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+
+    fn next(&mut self) -> Result<char, String> {
+        let c: Option<&char> = self.chars.get(self.position);
+        self.position += 1;
+        return c.copied().ok_or(String::from("Unexpected end of input"));
+    }
+
+    fn expect(&mut self, value: &str) -> Result<(), String> {
+        for expected in value.chars() {
+            if self.next()? != expected {
+                return Err(format!("Expected '{value}' at {}", self.position - 1));
+            }
+        }
+        return Ok(());
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.chars.get(self.position) {
+            Some('n') => {
+                self.expect("null")?;
+                return Ok(Json::Null);
+            }
+            Some('t') => {
+                self.expect("true")?;
+                return Ok(Json::Bool(true));
+            }
+            Some('f') => {
+                self.expect("false")?;
+                return Ok(Json::Bool(false));
+            }
+            Some('"') => return Ok(Json::String(self.parse_string()?)),
+            Some('[') => {
+                self.position += 1;
+                let mut values: Vec<Json> = vec![];
+                self.skip_whitespace();
+                if self.chars.get(self.position) == Some(&']') {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+                loop {
+                    values.push(self.parse_value()?);
+                    self.skip_whitespace();
+                    match self.next()? {
+                        ',' => continue,
+                        ']' => return Ok(Json::Array(values)),
+                        _ => return Err(format!("Expected ',' or ']' at {}", self.position - 1)),
+                    }
+                }
+            }
+            Some('{') => {
+                self.position += 1;
+                let mut entries: Vec<(String, Json)> = vec![];
+                self.skip_whitespace();
+                if self.chars.get(self.position) == Some(&'}') {
+                    self.position += 1;
+                    return Ok(Json::Object(entries));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.chars.get(self.position) != Some(&'"') {
+                        return Err(format!("Expected key at {}", self.position));
+                    }
+                    let key: String = self.parse_string()?;
+                    self.skip_whitespace();
+                    self.expect(":")?;
+                    entries.push((key, self.parse_value()?));
+                    self.skip_whitespace();
+                    match self.next()? {
+                        ',' => continue,
+                        '}' => return Ok(Json::Object(entries)),
+                        _ => return Err(format!("Expected ',' or '}}' at {}", self.position - 1)),
+                    }
+                }
+            }
+            Some(c) if c == &'-' || c.is_ascii_digit() => {
+                let start: usize = self.position;
+                while self
+                    .chars
+                    .get(self.position)
+                    .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(*c))
+                {
+                    self.position += 1;
+                }
+                let number: String = self.chars[start..self.position].iter().collect();
+                return number
+                    .parse::<f64>()
+                    .map(Json::Number)
+                    .map_err(|_| format!("Invalid number at {start}"));
+            }
+            Some(_) => return Err(format!("Unexpected character at {}", self.position)),
+            None => return Err(String::from("Unexpected end of input")),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect("\"")?;
+        let mut value: String = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(value),
+                '\\' => match self.next()? {
+                    '"' => value.push('"'),
+                    '\\' => value.push('\\'),
+                    '/' => value.push('/'),
+                    'b' => value.push('\u{8}'),
+                    'f' => value.push('\u{c}'),
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    'u' => {
+                        let mut code: u32 = self.parse_hex()?;
+                        // combine utf-16 surrogate pair
+                        if (0xD800..0xDC00).contains(&code)
+                            && self.chars.get(self.position) == Some(&'\\')
+                            && self.chars.get(self.position + 1) == Some(&'u')
+                        {
+                            self.position += 2;
+                            let low: u32 = self.parse_hex()?;
+                            if (0xDC00..0xE000).contains(&low) {
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            } else {
+                                // lone high surrogate, decode the next escape on its own
+                                self.position -= 6;
+                            }
+                        }
+                        value.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    _ => return Err(format!("Invalid escape at {}", self.position - 1)),
+                },
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, String> {
+        let mut hex: String = String::new();
+        for _ in 0..4 {
+            hex.push(self.next()?);
+        }
+        return u32::from_str_radix(&hex, 16).map_err(|_| format!("Invalid escape '{hex}'"));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_parse_literals() {
+        assert_eq!(Json::parse("null"), Ok(Json::Null));
+        assert_eq!(Json::parse(" true "), Ok(Json::Bool(true)));
+        assert_eq!(Json::parse("false"), Ok(Json::Bool(false)));
+        assert_eq!(Json::parse("-1.5e2"), Ok(Json::Number(-150.0)));
+        assert_eq!(Json::parse("42"), Ok(Json::Number(42.0)));
+    }
+
+    #[test]
+    fn test_json_parse_string() {
+        assert_eq!(
+            Json::parse(r#""a\"b\\c\nA😀""#),
+            Ok(Json::String(String::from("a\"b\\c\nA😀")))
+        );
+    }
+
+    #[test]
+    fn test_json_parse_string_lone_surrogate() {
+        assert_eq!(
+            Json::parse(r#""\uD800\u0041""#),
+            Ok(Json::String(String::from("\u{FFFD}A")))
+        );
+        assert_eq!(
+            Json::parse(r#""\uD800\uD83D\uDE00""#),
+            Ok(Json::String(String::from("\u{FFFD}😀")))
+        );
+        assert_eq!(
+            Json::parse(r#""\uDE00a""#),
+            Ok(Json::String(String::from("\u{FFFD}a")))
+        );
+    }
+
+    #[test]
+    fn test_json_parse_nested() {
+        let json: Json =
+            Json::parse(r#"{"id": 1, "params": {"list": [1, "a", null], "empty": {}}}"#).unwrap();
+        assert_eq!(json.get("id").and_then(|j| j.as_usize()), Some(1));
+        assert_eq!(
+            json.get("params")
+                .and_then(|j| j.get("list"))
+                .and_then(|j| j.as_array())
+                .map(|a| a.len()),
+            Some(3)
+        );
+        assert_eq!(
+            json.get("params").and_then(|j| j.get("empty")),
+            Some(&Json::Object(vec![]))
+        );
+        assert_eq!(json.get("missing"), None);
+    }
+
+    #[test]
+    fn test_json_parse_invalid() {
+        assert_eq!(Json::parse("").is_err(), true);
+        assert_eq!(Json::parse("{").is_err(), true);
+        assert_eq!(Json::parse("[1,]").is_err(), true);
+        assert_eq!(Json::parse(r#"{"a" 1}"#).is_err(), true);
+        assert_eq!(Json::parse("tru").is_err(), true);
+        assert_eq!(Json::parse("1 2").is_err(), true);
+    }
+
+    #[test]
+    fn test_json_to_string() {
+        let json: Json = Json::object(vec![
+            ("a", Json::Number(1.0)),
+            ("b", Json::Number(1.5)),
+            ("c", Json::String(String::from("x\"\n\u{1}"))),
+            ("d", Json::Array(vec![Json::Null, Json::Bool(true)])),
+        ]);
+        assert_eq!(
+            json.to_string(),
+            r#"{"a":1,"b":1.5,"c":"x\"\n\u0001","d":[null,true]}"#
+        );
+        assert_eq!(Json::parse(&json.to_string()), Ok(json));
+    }
+}
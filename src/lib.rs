@@ -0,0 +1,122 @@
+//! sqlfmt - SQL Format
+//!
+//! Library used by the `sqlfmt` command line program to format SQL.
+//!
+//! ```
+//! let options: sqlfmt::FormatOptions = sqlfmt::Configuration::builder()
+//!     .newlines(true)
+//!     .case(sqlfmt::ConfigCase::Uppercase)
+//!     .build();
+//!
+//! let sql: String = sqlfmt::format("select * from tbl1", &options).unwrap();
+//! assert_eq!(sql, "SELECT\n    *\nFROM tbl1");
+//! ```
+
+#![allow(clippy::needless_return)]
+
+// command line arguments of the binary, not part of the library API
+#[doc(hidden)]
+pub mod arguments;
+pub mod configuration;
+pub mod diagnostic;
+pub mod diff;
+pub mod files;
+pub mod format;
+pub mod json;
+pub mod lsp;
+pub mod token;
+
+pub use configuration::{
+    ConfigCase, ConfigCommaStyle, ConfigCommentStyle, ConfigDialect, ConfigLayout,
+    ConfigLineEndings, ConfigTab, Configuration, ConfigurationBuilder,
+};
+pub use diagnostic::{Diagnostic, DiagnosticKind};
+pub use token::{Token, TokenBehavior, TokenCategory, TokenPosition};
+
+/// Options used to format SQL, see [`Configuration::builder`].
+pub type FormatOptions = Configuration;
+
+/// Format SQL with the provided options.
+///
+/// Malformed SQL (unterminated quotes, unclosed block comments or unbalanced parentheses)
+/// is formatted as well as possible, see [`format_with_diagnostics`] to detect and refuse it.
+pub fn format(sql: &str, options: &FormatOptions) -> Result<String, FormatError> {
+    return Ok(format::get_formatted_sql(options, sql.to_string()));
+}
+
+/// Format SQL with the provided options, returning any problems found in the input.
+pub fn format_with_diagnostics(sql: &str, options: &FormatOptions) -> (String, Vec<Diagnostic>) {
+    return format::get_formatted_sql_and_diagnostics(options, sql.to_string());
+}
+
+/// Error returned when SQL could not be formatted.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatError {}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_default() {
+        let options: FormatOptions = Configuration::new();
+        assert_eq!(
+            format("SELECT  *   FROM TBL1", &options),
+            Ok(String::from("SELECT * FROM TBL1"))
+        );
+    }
+
+    #[test]
+    fn test_format_builder() {
+        let options: FormatOptions = Configuration::builder()
+            .newlines(true)
+            .case(ConfigCase::Lowercase)
+            .tabs(ConfigTab::Space(2))
+            .build();
+        assert_eq!(
+            format("SELECT C1, C2 FROM TBL1", &options),
+            Ok(String::from("select\n  C1,\n  C2\nfrom TBL1"))
+        );
+    }
+
+    #[test]
+    fn test_format_malformed() {
+        let options: FormatOptions = Configuration::new();
+        assert_eq!(
+            format("SELECT  (1\nFROM 'TBL1", &options),
+            Ok(String::from("SELECT (1\n        FROM 'TBL1"))
+        );
+
+        let (sql, diagnostics): (String, Vec<Diagnostic>) =
+            format_with_diagnostics("SELECT (1\nFROM 'TBL1", &options);
+        assert_eq!(sql, "SELECT (1\n        FROM 'TBL1");
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::new(DiagnosticKind::UnclosedParen, (1, 8), (1, 9)),
+                Diagnostic::new(DiagnosticKind::UnterminatedQuote, (2, 6), (2, 11)),
+            ]
+        );
+
+        let (sql, diagnostics): (String, Vec<Diagnostic>) =
+            format_with_diagnostics("SELECT 1)", &options);
+        assert_eq!(sql, "SELECT 1)");
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnmatchedParen,
+                (1, 9),
+                (1, 10)
+            )]
+        );
+    }
+}
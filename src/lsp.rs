@@ -0,0 +1,819 @@
+#![allow(clippy::needless_return)]
+
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::arguments::Arguments;
+use crate::configuration::{self, ConfigFile, ConfigTab, Configuration};
+use crate::diagnostic::Diagnostic;
+use crate::format::{self, FormatRange};
+use crate::json::Json;
+
+const ERROR_PARSE: f64 = -32700.0;
+const ERROR_INVALID_REQUEST: f64 = -32600.0;
+const ERROR_METHOD_NOT_FOUND: f64 = -32601.0;
+
+const TEXT_DOCUMENT_SYNC_FULL: f64 = 1.0;
+const DIAGNOSTIC_SEVERITY_ERROR: f64 = 1.0;
+const MESSAGE_TYPE_ERROR: f64 = 1.0;
+
+/// Run language server over stdio until exit, returning the exit code.
+///
+/// Documents use the config file found from their path, `config` is used for other documents.
+pub fn run(config: &Configuration, arguments: Arguments) -> i32 {
+    let mut server: Server = Server::new(config.clone()).with_arguments(arguments);
+    let mut reader: io::StdinLock = io::stdin().lock();
+    let mut writer: io::StdoutLock = io::stdout().lock();
+
+    loop {
+        let responses: Vec<Json> = match read_message(&mut reader) {
+            Ok(Some(Ok(message))) => {
+                if message.get("method").and_then(|m| m.as_str()) == Some("exit") {
+                    return if server.shutdown { 0 } else { 1 };
+                }
+                server.handle(&message)
+            }
+            Ok(Some(Err(_))) => vec![get_error_response(&Json::Null, ERROR_PARSE, "Parse error")],
+            Ok(None) => return 1,
+            Err(_) => return 1,
+        };
+
+        for response in responses {
+            if write_message(&mut writer, &response).is_err() {
+                return 1;
+            }
+        }
+    }
+}
+
+/// Read a `Content-Length` framed message, `None` at end of input.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Result<Json, String>>, io::Error> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut line: String = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line: &str = line.trim();
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("Content-Length")
+        {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let mut content: Vec<u8> = vec![0; content_length.unwrap_or(0)];
+    reader.read_exact(&mut content)?;
+    return Ok(Some(Json::parse(&String::from_utf8_lossy(&content))));
+}
+
+pub fn write_message<W: Write>(writer: &mut W, message: &Json) -> Result<(), io::Error> {
+    let content: String = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{content}", content.len())?;
+    return writer.flush();
+}
+
+pub struct Server {
+    config: Configuration,
+    arguments: Option<Arguments>,
+    documents: HashMap<String, String>,
+    configs: HashMap<String, Configuration>,
+    /// Documents with indentation set by arguments or their config file, over editor options.
+    indent_configs: HashSet<String>,
+    indent_config: bool,
+    shutdown: bool,
+}
+
+impl Server {
+    pub fn new(config: Configuration) -> Server {
+        Server {
+            config,
+            arguments: None,
+            documents: HashMap::new(),
+            configs: HashMap::new(),
+            indent_configs: HashSet::new(),
+            indent_config: false,
+            shutdown: false,
+        }
+    }
+
+    /// Resolve configuration of `file://` documents from their path, like input files.
+    pub fn with_arguments(mut self, arguments: Arguments) -> Server {
+        let config_file: Option<ConfigFile> = configuration::get_config_file(&arguments, None)
+            .ok()
+            .flatten();
+        self.indent_config = is_indent_config(&arguments, config_file.as_ref(), None);
+        self.arguments = Some(arguments);
+        self
+    }
+
+    /// Handle a message, returning the messages to send back.
+    pub fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let method: &str = message.get("method").and_then(|m| m.as_str()).unwrap_or("");
+        let params: &Json = message.get("params").unwrap_or(&Json::Null);
+
+        let Some(id) = message.get("id") else {
+            return self.handle_notification(method, params);
+        };
+
+        if self.shutdown {
+            return vec![get_error_response(
+                id,
+                ERROR_INVALID_REQUEST,
+                "Server is shut down",
+            )];
+        }
+
+        let result: Json = match method {
+            "initialize" => get_initialize_result(),
+            "shutdown" => {
+                self.shutdown = true;
+                Json::Null
+            }
+            "textDocument/formatting" => self.get_formatting_edits(params, None),
+            "textDocument/rangeFormatting" => {
+                let range: Option<FormatRange> = self.get_params_range(params);
+                match range {
+                    Some(range) => self.get_formatting_edits(params, Some(range)),
+                    None => Json::Null,
+                }
+            }
+            "textDocument/onTypeFormatting" => {
+                let offset: Option<usize> = self.get_params_offset(params, params.get("position"));
+                match offset {
+                    Some(offset) => {
+                        self.get_formatting_edits(params, Some(FormatRange::Bytes(offset, offset)))
+                    }
+                    None => Json::Null,
+                }
+            }
+            _ => {
+                return vec![get_error_response(
+                    id,
+                    ERROR_METHOD_NOT_FOUND,
+                    &format!("Method not found: {method}"),
+                )];
+            }
+        };
+
+        return vec![Json::object(vec![
+            ("jsonrpc", Json::String(String::from("2.0"))),
+            ("id", id.clone()),
+            ("result", result),
+        ])];
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Json) -> Vec<Json> {
+        let Some(uri) = get_params_uri(params) else {
+            return vec![];
+        };
+
+        match method {
+            "textDocument/didOpen" => {
+                let text: Option<&str> = params
+                    .get("textDocument")
+                    .and_then(|d| d.get("text"))
+                    .and_then(|t| t.as_str());
+                if let Some(text) = text {
+                    self.documents.insert(uri.to_string(), text.to_string());
+                }
+                if let Some(message) = self.update_config(uri) {
+                    return vec![message, self.get_publish_diagnostics(uri)];
+                }
+            }
+            "textDocument/didChange" => {
+                // full document sync, last change has the whole text
+                let text: Option<&str> = params
+                    .get("contentChanges")
+                    .and_then(|c| c.as_array())
+                    .and_then(|c| c.last())
+                    .and_then(|c| c.get("text"))
+                    .and_then(|t| t.as_str());
+                if let Some(text) = text {
+                    self.documents.insert(uri.to_string(), text.to_string());
+                }
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri);
+                self.configs.remove(uri);
+                self.indent_configs.remove(uri);
+            }
+            _ => return vec![],
+        }
+
+        return vec![self.get_publish_diagnostics(uri)];
+    }
+
+    /// Update document configuration, returning a message to show if the config file is invalid.
+    fn update_config(&mut self, uri: &str) -> Option<Json> {
+        let arguments: &Arguments = self.arguments.as_ref()?;
+        let path: PathBuf = get_uri_path(uri)?;
+        let config: Result<(Configuration, bool), configuration::ConfigError> =
+            configuration::get_config_file(arguments, Some(&path)).and_then(|config_file| {
+                let config: Configuration =
+                    Configuration::from_config_file(arguments, config_file.as_ref(), Some(&path))?;
+                let indent_config: bool =
+                    is_indent_config(arguments, config_file.as_ref(), Some(&path));
+                return Ok((config, indent_config));
+            });
+        match config {
+            Ok((config, indent_config)) => {
+                self.configs.insert(uri.to_string(), config);
+                if indent_config {
+                    self.indent_configs.insert(uri.to_string());
+                } else {
+                    self.indent_configs.remove(uri);
+                }
+                return None;
+            }
+            Err(error) => {
+                self.configs.remove(uri);
+                self.indent_configs.remove(uri);
+                return Some(Json::object(vec![
+                    ("jsonrpc", Json::String(String::from("2.0"))),
+                    ("method", Json::String(String::from("window/showMessage"))),
+                    (
+                        "params",
+                        Json::object(vec![
+                            ("type", Json::Number(MESSAGE_TYPE_ERROR)),
+                            ("message", Json::String(format!("sqlfmt: {error}"))),
+                        ]),
+                    ),
+                ]));
+            }
+        }
+    }
+
+    fn get_config(&self, uri: &str) -> &Configuration {
+        return self.configs.get(uri).unwrap_or(&self.config);
+    }
+
+    fn get_publish_diagnostics(&self, uri: &str) -> Json {
+        let mut diagnostics: Vec<Json> = vec![];
+        if let Some(text) = self.documents.get(uri) {
+            let (_, found): (String, Vec<Diagnostic>) =
+                format::get_formatted_sql_and_diagnostics(self.get_config(uri), text.clone());
+            for diagnostic in found {
+                let start: Json =
+                    get_position_json(text, diagnostic.line - 1, diagnostic.column - 1);
+                let end: Json =
+                    get_position_json(text, diagnostic.end_line - 1, diagnostic.end_column - 1);
+                diagnostics.push(Json::object(vec![
+                    ("range", Json::object(vec![("start", start), ("end", end)])),
+                    ("severity", Json::Number(DIAGNOSTIC_SEVERITY_ERROR)),
+                    ("source", Json::String(String::from("sqlfmt"))),
+                    ("message", Json::String(diagnostic.message().to_string())),
+                ]));
+            }
+        }
+
+        return Json::object(vec![
+            ("jsonrpc", Json::String(String::from("2.0"))),
+            (
+                "method",
+                Json::String(String::from("textDocument/publishDiagnostics")),
+            ),
+            (
+                "params",
+                Json::object(vec![
+                    ("uri", Json::String(uri.to_string())),
+                    ("diagnostics", Json::Array(diagnostics)),
+                ]),
+            ),
+        ]);
+    }
+
+    fn get_formatting_edits(&self, params: &Json, range: Option<FormatRange>) -> Json {
+        let Some(uri) = get_params_uri(params) else {
+            return Json::Null;
+        };
+        let Some(text) = self.documents.get(uri) else {
+            return Json::Null;
+        };
+
+        let mut config: Configuration = self.get_config(uri).clone();
+        let is_indent_config: bool = if self.configs.contains_key(uri) {
+            self.indent_configs.contains(uri)
+        } else {
+            self.indent_config
+        };
+        if !is_indent_config && let Some(tabs) = get_params_tabs(params) {
+            config.tabs = tabs;
+        }
+
+        let mut formatted: String = match range {
+            Some(range) => format::get_formatted_sql_range(&config, text, &range),
+            None => format::get_formatted_sql(&config, text.clone()),
+        };
+
+        // keep final newline of document
+        if text.ends_with('\n') && !formatted.ends_with('\n') {
+            formatted.push_str(format::get_line_endings(&config, text).get_value());
+        }
+
+        if &formatted == text {
+            return Json::Array(vec![]);
+        }
+
+        let (changed, new_text): (Range<usize>, &str) = get_changed_lines(text, &formatted);
+        let (start_line, start_character): (usize, usize) =
+            get_end_position(&text[..changed.start]);
+        let (end_line, end_character): (usize, usize) = get_end_position(&text[..changed.end]);
+        return Json::Array(vec![Json::object(vec![
+            (
+                "range",
+                Json::object(vec![
+                    ("start", get_position(start_line, start_character)),
+                    ("end", get_position(end_line, end_character)),
+                ]),
+            ),
+            ("newText", Json::String(new_text.to_string())),
+        ])]);
+    }
+
+    fn get_params_range(&self, params: &Json) -> Option<FormatRange> {
+        let range: &Json = params.get("range")?;
+        let start: usize = self.get_params_offset(params, range.get("start"))?;
+        let end: usize = self.get_params_offset(params, range.get("end"))?;
+        return Some(FormatRange::Bytes(start, end));
+    }
+
+    fn get_params_offset(&self, params: &Json, position: Option<&Json>) -> Option<usize> {
+        let text: &String = self.documents.get(get_params_uri(params)?)?;
+        let position: &Json = position?;
+        let line: usize = position.get("line")?.as_usize()?;
+        let character: usize = position.get("character")?.as_usize()?;
+        return Some(get_offset(text, line, character));
+    }
+}
+
+fn get_initialize_result() -> Json {
+    return Json::object(vec![
+        (
+            "capabilities",
+            Json::object(vec![
+                ("textDocumentSync", Json::Number(TEXT_DOCUMENT_SYNC_FULL)),
+                ("documentFormattingProvider", Json::Bool(true)),
+                ("documentRangeFormattingProvider", Json::Bool(true)),
+                (
+                    "documentOnTypeFormattingProvider",
+                    Json::object(vec![(
+                        "firstTriggerCharacter",
+                        Json::String(String::from(";")),
+                    )]),
+                ),
+            ]),
+        ),
+        (
+            "serverInfo",
+            Json::object(vec![
+                ("name", Json::String(String::from("sqlfmt"))),
+                (
+                    "version",
+                    Json::String(String::from(env!("CARGO_PKG_VERSION"))),
+                ),
+            ]),
+        ),
+    ]);
+}
+
+fn get_error_response(id: &Json, code: f64, message: &str) -> Json {
+    return Json::object(vec![
+        ("jsonrpc", Json::String(String::from("2.0"))),
+        ("id", id.clone()),
+        (
+            "error",
+            Json::object(vec![
+                ("code", Json::Number(code)),
+                ("message", Json::String(message.to_string())),
+            ]),
+        ),
+    ]);
+}
+
+fn get_params_uri(params: &Json) -> Option<&str> {
+    return params.get("textDocument")?.get("uri")?.as_str();
+}
+
+/// Get indentation of the editor formatting options.
+fn get_params_tabs(params: &Json) -> Option<ConfigTab> {
+    let options: &Json = params.get("options")?;
+    if !options.get("insertSpaces")?.as_bool()? {
+        return Some(ConfigTab::Tab);
+    }
+    let tab_size: u16 = u16::try_from(options.get("tabSize")?.as_usize()?).ok()?;
+    return Some(ConfigTab::Space(tab_size));
+}
+
+/// Check if arguments or the config file set the indentation, used over editor options.
+fn is_indent_config(
+    arguments: &Arguments,
+    config_file: Option<&ConfigFile>,
+    input: Option<&Path>,
+) -> bool {
+    return arguments.tabs
+        || arguments.spaces.is_some()
+        || config_file.is_some_and(|f| f.has_key("tabs", input) || f.has_key("spaces", input));
+}
+
+/// Get byte range of the lines changed in text, and the formatted lines replacing them.
+fn get_changed_lines<'a>(text: &str, formatted: &'a str) -> (Range<usize>, &'a str) {
+    let mut start: usize = 0;
+    for (line, formatted_line) in text
+        .split_inclusive('\n')
+        .zip(formatted.split_inclusive('\n'))
+    {
+        if line != formatted_line || !line.ends_with('\n') {
+            break;
+        }
+        start += line.len();
+    }
+
+    let mut end: usize = 0;
+    for (line, formatted_line) in text[start..]
+        .split_inclusive('\n')
+        .rev()
+        .zip(formatted[start..].split_inclusive('\n').rev())
+    {
+        if line != formatted_line {
+            break;
+        }
+        end += line.len();
+    }
+
+    return (
+        start..text.len() - end,
+        &formatted[start..formatted.len() - end],
+    );
+}
+
+/// Get file path of a `file://` URI, decoding percent escapes.
+fn get_uri_path(uri: &str) -> Option<PathBuf> {
+    let uri: &str = uri.strip_prefix("file://")?;
+    // skip authority, usually empty
+    let uri: &str = &uri[uri.find('/')?..];
+
+    let mut bytes: Vec<u8> = vec![];
+    let mut i: usize = 0;
+    while i < uri.len() {
+        let byte: u8 = uri.as_bytes()[i];
+        if byte == b'%'
+            && let Some(hex) = uri.get(i + 1..i + 3)
+            && let Ok(value) = u8::from_str_radix(hex, 16)
+        {
+            bytes.push(value);
+            i += 3;
+            continue;
+        }
+        bytes.push(byte);
+        i += 1;
+    }
+
+    let path: String = String::from_utf8(bytes).ok()?;
+    // windows drive letter, as in /c:/dir/file.sql
+    let chars: Vec<char> = path.chars().take(3).collect();
+    if chars.len() == 3 && chars[1].is_ascii_alphabetic() && chars[2] == ':' {
+        return Some(PathBuf::from(&path[1..]));
+    }
+    return Some(PathBuf::from(path));
+}
+
+fn get_position(line: usize, character: usize) -> Json {
+    return Json::object(vec![
+        ("line", Json::Number(line as f64)),
+        ("character", Json::Number(character as f64)),
+    ]);
+}
+
+/// Get LSP position of a 0-based line and character column, counting UTF-16 units.
+fn get_position_json(text: &str, line: usize, column: usize) -> Json {
+    let line_text: &str = text.split('\n').nth(line).unwrap_or("");
+    let character: usize = line_text
+        .chars()
+        .filter(|c| c != &'\r')
+        .take(column)
+        .map(|c| c.len_utf16())
+        .sum();
+    return get_position(line, character);
+}
+
+/// Get byte offset of a 0-based line and UTF-16 character position.
+fn get_offset(text: &str, line: usize, character: usize) -> usize {
+    let mut offset: usize = 0;
+    for (i, line_text) in text.split_inclusive('\n').enumerate() {
+        if i < line {
+            offset += line_text.len();
+            continue;
+        }
+
+        let mut units: usize = 0;
+        for c in line_text.chars() {
+            if units >= character || c == '\r' || c == '\n' {
+                break;
+            }
+            units += c.len_utf16();
+            offset += c.len_utf8();
+        }
+        return offset;
+    }
+    return text.len();
+}
+
+fn get_end_position(text: &str) -> (usize, usize) {
+    let line: usize = text.matches('\n').count();
+    let last_line: &str = text.rsplit('\n').next().unwrap_or("");
+    return (line, last_line.encode_utf16().count());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configuration::ConfigCase;
+
+    fn get_server() -> Server {
+        return Server::new(Configuration::builder().case(ConfigCase::Uppercase).build());
+    }
+
+    fn open_document(server: &mut Server, text: &str) -> Vec<Json> {
+        let message: Json = Json::object(vec![
+            ("method", Json::String(String::from("textDocument/didOpen"))),
+            (
+                "params",
+                Json::object(vec![(
+                    "textDocument",
+                    Json::object(vec![
+                        ("uri", Json::String(String::from("file:///a.sql"))),
+                        ("text", Json::String(String::from(text))),
+                    ]),
+                )]),
+            ),
+        ]);
+        return server.handle(&message);
+    }
+
+    #[test]
+    fn test_lsp_read_write_message() {
+        let mut output: Vec<u8> = vec![];
+        let message: Json = Json::object(vec![("id", Json::Number(1.0))]);
+        write_message(&mut output, &message).unwrap();
+        assert_eq!(
+            String::from_utf8(output.clone()).unwrap(),
+            "Content-Length: 8\r\n\r\n{\"id\":1}"
+        );
+
+        let mut reader: io::Cursor<Vec<u8>> = io::Cursor::new(output);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(Ok(message)));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn test_lsp_initialize_shutdown() {
+        let mut server: Server = get_server();
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0]
+                .get("result")
+                .and_then(|r| r.get("capabilities"))
+                .and_then(|c| c.get("documentFormattingProvider")),
+            Some(&Json::Bool(true))
+        );
+
+        let response: Vec<Json> =
+            server.handle(&Json::parse(r#"{"jsonrpc":"2.0","id":2,"method":"shutdown"}"#).unwrap());
+        assert_eq!(
+            response[0].to_string(),
+            r#"{"jsonrpc":"2.0","id":2,"result":null}"#
+        );
+
+        let response: Vec<Json> = server
+            .handle(&Json::parse(r#"{"jsonrpc":"2.0","id":3,"method":"initialize"}"#).unwrap());
+        assert_eq!(
+            response[0].get("error").and_then(|e| e.get("code")),
+            Some(&Json::Number(ERROR_INVALID_REQUEST))
+        );
+    }
+
+    #[test]
+    fn test_lsp_unknown_method() {
+        let mut server: Server = get_server();
+        let response: Vec<Json> = server
+            .handle(&Json::parse(r#"{"jsonrpc":"2.0","id":"a","method":"unknown"}"#).unwrap());
+        assert_eq!(
+            response[0].to_string(),
+            r#"{"jsonrpc":"2.0","id":"a","error":{"code":-32601,"message":"Method not found: unknown"}}"#
+        );
+
+        let response: Vec<Json> =
+            server.handle(&Json::parse(r#"{"jsonrpc":"2.0","method":"unknown"}"#).unwrap());
+        assert_eq!(response, vec![]);
+    }
+
+    #[test]
+    fn test_lsp_diagnostics() {
+        let mut server: Server = get_server();
+        let response: Vec<Json> = open_document(&mut server, "select 1;\nselect '😀\n");
+        assert_eq!(
+            response[0].to_string(),
+            r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///a.sql","diagnostics":[{"range":{"start":{"line":1,"character":7},"end":{"line":2,"character":0}},"severity":1,"source":"sqlfmt","message":"unterminated quote"}]}}"#
+        );
+    }
+
+    #[test]
+    fn test_lsp_formatting() {
+        let mut server: Server = get_server();
+        open_document(&mut server, "select  1;\nselect  2;\n");
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":1,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///a.sql"},"options":{"tabSize":4,"insertSpaces":true}}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0].get("result").map(|r| r.to_string()),
+            Some(String::from(
+                r#"[{"range":{"start":{"line":0,"character":0},"end":{"line":2,"character":0}},"newText":"SELECT 1;\nSELECT 2;\n"}]"#
+            ))
+        );
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/rangeFormatting","params":{"textDocument":{"uri":"file:///a.sql"},"range":{"start":{"line":1,"character":0},"end":{"line":1,"character":3}}}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0].get("result").map(|r| r.to_string()),
+            Some(String::from(
+                r#"[{"range":{"start":{"line":1,"character":0},"end":{"line":2,"character":0}},"newText":"SELECT 2;\n"}]"#
+            ))
+        );
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":3,"method":"textDocument/onTypeFormatting","params":{"textDocument":{"uri":"file:///a.sql"},"position":{"line":0,"character":10},"ch":";"}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0].get("result").map(|r| r.to_string()),
+            Some(String::from(
+                r#"[{"range":{"start":{"line":0,"character":0},"end":{"line":1,"character":0}},"newText":"SELECT 1;\n"}]"#
+            ))
+        );
+    }
+
+    #[test]
+    fn test_lsp_formatting_options() {
+        let mut server: Server = Server::new(Configuration::builder().newlines(true).build());
+        open_document(&mut server, "SELECT\n  1;\n\nselect a, b from t;\n");
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":1,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///a.sql"},"options":{"tabSize":2,"insertSpaces":true}}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0].get("result").map(|r| r.to_string()),
+            Some(String::from(
+                r#"[{"range":{"start":{"line":3,"character":0},"end":{"line":4,"character":0}},"newText":"select\n  a,\n  b\nfrom t;\n"}]"#
+            ))
+        );
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///a.sql"},"options":{"tabSize":2,"insertSpaces":false}}}"#).unwrap(),
+        );
+        assert_eq!(
+            response[0]
+                .get("result")
+                .and_then(|r| r.as_array())
+                .and_then(|r| r[0].get("newText")),
+            Some(&Json::String(String::from(
+                "\t1;\n\nselect\n\ta,\n\tb\nfrom t;\n"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_lsp_formatting_unchanged() {
+        let mut server: Server = get_server();
+        open_document(&mut server, "SELECT 1;\n");
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":1,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///a.sql"}}}"#).unwrap(),
+        );
+        assert_eq!(response[0].get("result"), Some(&Json::Array(vec![])));
+
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///b.sql"}}}"#).unwrap(),
+        );
+        assert_eq!(response[0].get("result"), Some(&Json::Null));
+
+        open_document(&mut server, "SELECT 1;\r\nSELECT 2;\r\n");
+        let response: Vec<Json> = server.handle(
+            &Json::parse(r#"{"jsonrpc":"2.0","id":3,"method":"textDocument/formatting","params":{"textDocument":{"uri":"file:///a.sql"}}}"#).unwrap(),
+        );
+        assert_eq!(response[0].get("result"), Some(&Json::Array(vec![])));
+    }
+
+    #[test]
+    fn test_lsp_document_config() {
+        let root: PathBuf = std::env::temp_dir().join(format!(
+            "sqlfmt_test_lsp_document_config_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("a")).unwrap();
+        std::fs::create_dir_all(root.join("b")).unwrap();
+        std::fs::write(root.join("a/.sqlfmt"), "lower\n").unwrap();
+        std::fs::write(root.join("b/.sqlfmt"), "lower = maybe\n").unwrap();
+        std::fs::create_dir_all(root.join("c")).unwrap();
+        std::fs::write(root.join("c/.sqlfmt"), "newlines\nspaces = 2\n").unwrap();
+
+        let arguments: Arguments = Arguments::from(vec![].into_iter()).unwrap();
+        let mut server: Server = get_server().with_arguments(arguments);
+        let uri: String = format!("file://{}", root.join("a/x.sql").display());
+        let message: String = format!(
+            r#"{{"method":"textDocument/didOpen","params":{{"textDocument":{{"uri":"{uri}","text":"SELECT 1;"}}}}}}"#
+        );
+        let response: Vec<Json> = server.handle(&Json::parse(&message).unwrap());
+        assert_eq!(response.len(), 1);
+
+        let message: String = format!(
+            r#"{{"id":1,"method":"textDocument/formatting","params":{{"textDocument":{{"uri":"{uri}"}}}}}}"#
+        );
+        let response: Vec<Json> = server.handle(&Json::parse(&message).unwrap());
+        assert_eq!(
+            response[0]
+                .get("result")
+                .and_then(|r| r.as_array())
+                .and_then(|r| r[0].get("newText")),
+            Some(&Json::String(String::from("select 1;")))
+        );
+
+        let uri: String = format!("file://{}", root.join("b/x.sql").display());
+        let message: String = format!(
+            r#"{{"method":"textDocument/didOpen","params":{{"textDocument":{{"uri":"{uri}","text":"select 1;"}}}}}}"#
+        );
+        let response: Vec<Json> = server.handle(&Json::parse(&message).unwrap());
+        assert_eq!(
+            response[0].get("method"),
+            Some(&Json::String(String::from("window/showMessage")))
+        );
+        assert_eq!(
+            response[1].get("method"),
+            Some(&Json::String(String::from(
+                "textDocument/publishDiagnostics"
+            )))
+        );
+
+        // config file indentation is used over editor options
+        let uri: String = format!("file://{}", root.join("c/x.sql").display());
+        let message: String = format!(
+            r#"{{"method":"textDocument/didOpen","params":{{"textDocument":{{"uri":"{uri}","text":"SELECT 1;"}}}}}}"#
+        );
+        server.handle(&Json::parse(&message).unwrap());
+        let message: String = format!(
+            r#"{{"id":2,"method":"textDocument/formatting","params":{{"textDocument":{{"uri":"{uri}"}},"options":{{"tabSize":8,"insertSpaces":false}}}}}}"#
+        );
+        let response: Vec<Json> = server.handle(&Json::parse(&message).unwrap());
+        assert_eq!(
+            response[0]
+                .get("result")
+                .and_then(|r| r.as_array())
+                .and_then(|r| r[0].get("newText")),
+            Some(&Json::String(String::from("SELECT\n  1;")))
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_lsp_get_uri_path() {
+        assert_eq!(
+            get_uri_path("file:///home/a%20b/x.sql"),
+            Some(PathBuf::from("/home/a b/x.sql"))
+        );
+        assert_eq!(
+            get_uri_path("file://host/x%2.sql"),
+            Some(PathBuf::from("/x%2.sql"))
+        );
+        assert_eq!(
+            get_uri_path("file:///c%3A/dir/x.sql"),
+            Some(PathBuf::from("c:/dir/x.sql"))
+        );
+        assert_eq!(get_uri_path("untitled:Untitled-1"), None);
+    }
+
+    #[test]
+    fn test_lsp_get_offset() {
+        let text: &str = "ab\r\n😀c\nd";
+        assert_eq!(get_offset(text, 0, 0), 0);
+        assert_eq!(get_offset(text, 0, 9), 2);
+        assert_eq!(get_offset(text, 1, 2), 8);
+        assert_eq!(get_offset(text, 1, 3), 9);
+        assert_eq!(get_offset(text, 2, 1), 11);
+        assert_eq!(get_offset(text, 5, 0), 11);
+        assert_eq!(get_end_position(text), (2, 1));
+    }
+}
@@ -1,12 +1,12 @@
+#![allow(clippy::needless_return)]
+
 use std::env;
 use std::fs;
 use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 use std::process;
 
-mod arguments;
-mod configuration;
-mod format;
-mod token;
+use sqlfmt::{Diagnostic, arguments, configuration, diff, files, format, lsp};
 
 fn main() {
     let args: Result<arguments::Arguments, &str> = arguments::Arguments::from(env::args().skip(1));
@@ -26,33 +26,125 @@ fn main() {
         process::exit(0);
     }
 
-    let config: configuration::Configuration = configuration::Configuration::from(&args);
+    if args.lsp {
+        let config: Result<configuration::Configuration, configuration::ConfigError> =
+            configuration::Configuration::from(&args);
+        if config.is_err() {
+            print_error(config.err().unwrap().to_string().as_str());
+            process::exit(1);
+        }
+        process::exit(lsp::run(&config.unwrap(), args));
+    }
+
+    let inputs: Result<Vec<Option<PathBuf>>, io::Error> = get_inputs(&args);
+    if inputs.is_err() {
+        print_error(inputs.err().unwrap().to_string().as_str());
+        process::exit(1);
+    }
+    let inputs: Vec<Option<PathBuf>> = inputs.unwrap();
+
+    if args.print_config {
+        print_config(&args, &inputs);
+        process::exit(0);
+    }
+
+    if args.output.is_some() && inputs.len() > 1 {
+        print_error("Output file can only be used with a single input.");
+        process::exit(1);
+    }
 
-    let sql_in: Result<String, io::Error> = get_input_sql(&args.input);
-    if sql_in.is_err() {
-        print_error(sql_in.err().unwrap().to_string().as_str());
+    if args.range.is_some() && inputs.len() > 1 {
+        print_error("Range can only be used with a single input.");
         process::exit(1);
     }
-    let sql_in: String = sql_in.unwrap();
 
-    let sql_out: String = format::get_formatted_sql(&config, sql_in);
+    if args.write && inputs.contains(&None) {
+        print_error("Write requires input files.");
+        process::exit(1);
+    }
+
+    let mut changed: bool = false;
+    let mut malformed: bool = false;
+    for input in &inputs {
+        let config: Result<configuration::Configuration, configuration::ConfigError> =
+            configuration::Configuration::from_path(&args, input.as_deref());
+        if config.is_err() {
+            print_error(config.err().unwrap().to_string().as_str());
+            process::exit(1);
+        }
+        let config: configuration::Configuration = config.unwrap();
+
+        let sql_in: Result<String, io::Error> = get_input_sql(input);
+        if sql_in.is_err() {
+            print_error(sql_in.err().unwrap().to_string().as_str());
+            process::exit(1);
+        }
+        let sql_in: String = sql_in.unwrap();
 
-    if args.output.is_some() {
-        match fs::write(args.output.unwrap(), &sql_out) {
-            Ok(_) => (),
-            Err(result) => {
-                print_error(result.to_string().as_str());
-                process::exit(1);
+        let (mut sql_out, diagnostics): (String, Vec<Diagnostic>) =
+            format::get_formatted_sql_and_diagnostics(&config, sql_in.clone());
+        if !diagnostics.is_empty() {
+            print_diagnostics(&diagnostics, input, &sql_in, args.strict);
+            if args.strict {
+                malformed = true;
+                continue;
             }
         }
-    } else {
-        println!("{sql_out}");
+
+        if let Some(range) = &args.range {
+            sql_out = format::get_formatted_sql_range(&config, &sql_in, range);
+        }
+
+        if sql_out != sql_in {
+            changed = true;
+        }
+
+        if args.diff {
+            let input_name: String = get_input_name(input);
+            let names: Option<(&str, &str)> = if inputs.len() > 1 {
+                Some((&input_name, &input_name))
+            } else {
+                None
+            };
+            print!("{}", diff::get_unified_diff(&sql_in, &sql_out, names));
+            continue;
+        }
+
+        if args.check {
+            if sql_out != sql_in {
+                println!("{}", get_input_name(input));
+            }
+            continue;
+        }
+
+        if args.write {
+            if sql_out != sql_in {
+                write_output_sql(input.as_ref().unwrap(), &sql_out);
+            }
+            continue;
+        }
+
+        if let Some(output) = &args.output {
+            write_output_sql(Path::new(output), &sql_out);
+        } else {
+            let line_ending: &str = format::get_line_endings(&config, &sql_in).get_value();
+            print!("{sql_out}{line_ending}");
+        }
+    }
+
+    if malformed {
+        process::exit(1);
+    }
+
+    if args.check && changed {
+        process::exit(2);
     }
 
     process::exit(0);
 }
 
 const FONT_RED: &str = "\x1b[31m";
+const FONT_YELLOW: &str = "\x1b[33m";
 const FONT_RESET: &str = "\x1b[0m";
 
 fn print_error(msg: &str) {
@@ -60,6 +152,54 @@ fn print_error(msg: &str) {
     println!("Run with -h/--help to print help.");
 }
 
+fn print_diagnostics(diagnostics: &[Diagnostic], input: &Option<PathBuf>, sql: &str, strict: bool) {
+    let (level, color): (&str, &str) = if strict {
+        ("error", FONT_RED)
+    } else {
+        ("warning", FONT_YELLOW)
+    };
+    let input_name: String = get_input_name(input);
+    for diagnostic in diagnostics {
+        let report: String = diagnostic.get_report(level, &input_name, sql);
+        let report: String = report.replacen(level, &format!("{color}{level}{FONT_RESET}"), 1);
+        eprintln!("{report}");
+    }
+}
+
+fn print_config(args: &arguments::Arguments, inputs: &[Option<PathBuf>]) {
+    for (i, input) in inputs.iter().enumerate() {
+        let config_file: Result<Option<configuration::ConfigFile>, configuration::ConfigError> =
+            configuration::get_config_file(args, input.as_deref());
+        if config_file.is_err() {
+            print_error(config_file.err().unwrap().to_string().as_str());
+            process::exit(1);
+        }
+        let config_file: Option<configuration::ConfigFile> = config_file.unwrap();
+
+        let config: Result<configuration::Configuration, configuration::ConfigError> =
+            configuration::Configuration::from_config_file(
+                args,
+                config_file.as_ref(),
+                input.as_deref(),
+            );
+        if config.is_err() {
+            print_error(config.err().unwrap().to_string().as_str());
+            process::exit(1);
+        }
+
+        let source: String = match config_file.and_then(|f| f.path) {
+            Some(path) => path.display().to_string(),
+            None => String::from("none"),
+        };
+        if i > 0 {
+            println!();
+        }
+        println!("# input: {}", get_input_name(input));
+        println!("# config file: {source}");
+        println!("{}", config.unwrap());
+    }
+}
+
 fn print_help() {
     println!(
         "sqlfmt - SQL Format
@@ -67,6 +207,8 @@ fn print_help() {
 Usage:
   <INPUT_STREAM> | sqlfmt
   sqlfmt -i <INPUT_FILE_PATH>
+  sqlfmt [OPTIONS] <PATH>...
+  sqlfmt lsp [OPTIONS]
 
 Arguments:
   Basic
@@ -76,33 +218,91 @@ Arguments:
   IO
     -i, --input  <FILE_PATH> Define path to input SQL file
     -o, --output <FILE_PATH> Define path to output SQL file
+    <PATH>...                Define paths to input SQL files or directories
+    --include    <GLOB>      Only format directory files matching glob
+    --exclude    <GLOB>      Skip directory files and directories matching glob
+    --range      <START:END> Only format statements overlapping lines START to END (inclusive)
+    --byte-range <START:END> Only format statements overlapping byte offsets START to END
+    --config     <FILE_PATH> Define path to config file (instead of looking it up)
+    --no-config              Do not use a config file
+
+  Mode
+    --check     Print input that would change when formatted (exit code 2)
+    --diff      Print unified diff of changes instead of formatted SQL
+    -w, --write Write formatted SQL back to input files that changed
+    --strict    Do not format malformed input, report it as an error (exit code 1)
+    --print-config Print configuration used for each input and the config file it came from
 
   Format Configuration
     -n, --newlines      Replace newlines
     --comment-pre-space Replace comment pre-space with indent level
     --align-text-groups Align text by groups inside parentheses
+    --collapse-short-statements Keep statements fitting in chars on one line (with newlines)
+    --collapse-short-clauses Keep WHERE and ORDER BY clauses fitting in chars on one line (with newlines)
+    --align-aliases     Align AS aliases in SELECT lists
+    --align-assignments Align = in SET assignments
+    --align-case-then   Align THEN in CASE branches
+    --wrap-comments     Wrap long comment lines at word boundaries to fit comment chars
+    --indent-block-comments Re-indent inner lines of block comments with the comment
+    --block-comment-gutters Start inner lines of block comments with a * gutter
     -u, --upper         Uppercase keywords
     -l, --lower         Lowercase keywords
+    --consistent        Use the case most keywords already have
+    --identifier-case <CASE> Define case of unquoted identifiers: upper, lower, preserve, consistent (default preserve)
+    --function-case <CASE> Define case of functions: upper, lower, preserve, consistent (default keyword case)
+    --datatype-case <CASE> Define case of data types: upper, lower, preserve, consistent (default keyword case)
     -t, --tabs          Use tabs for indents
     -s, --spaces <INT>  Define amount of spaces per indent (default 4)
     -c, --chars  <INT>  Define amount of max chars per line before break (default 80)
+    --comment-chars <INT> Define amount of max chars per line with a comment (default chars)
+    --paren-chars <INT> Define amount of max chars of parentheses kept inline (default chars)
+    --comma-style <STYLE> Define comma placement on new lines: leading, trailing (default trailing)
+    --layout <NAME>     Define clause keyword layout: standard, river (right-aligned keywords) (default standard)
+    --comment-style <STYLE> Convert comments: preserve, line (--), block (/* */) (default preserve)
+    --line-endings <NAME> Define line endings: auto (most found in input), lf, crlf (default auto)
+    -d, --dialect <NAME> Define SQL dialect: generic, postgres, mysql, tsql, sqlite, bigquery, snowflake (default generic)
 
 Config File:
-  .sqlfmt
+  sqlfmt.toml or .sqlfmt
 
-  This program will look for the config file in the current working directory and up (until root).
+  This program will look for the config file in the input file directory (or current working directory) and up (until root).
+  Use --config to define the config file instead, or --no-config to not use one.
   If found, the file content sets the default configuration values.
   Any configuration arguments provided will override these defaults.
 
+  Each line is a `key = value` pair, a `key` alone enables it and `#` starts a comment.
+  Strings can be quoted as TOML strings, lists can span lines, unknown keys and invalid values are reported as errors.
+  Keys can be in a [tool.sqlfmt] section, [[overrides]] sections with `files = [<GLOB>...]`
+  apply their keys to input files matching the globs (relative to the config file).
+
   Format Configuration
-    newlines
-    comment_pre_space
-    align_text_groups
-    upper
-    lower
-    tabs
-    spaces=<INT>
-    chars=<INT>"
+    newlines = <BOOL>
+    comment_pre_space = <BOOL>
+    align_text_groups = <BOOL>
+    collapse_short_statements = <BOOL>
+    collapse_short_clauses = <BOOL>
+    align_aliases = <BOOL>
+    align_assignments = <BOOL>
+    align_case_then = <BOOL>
+    wrap_comments = <BOOL>
+    indent_block_comments = <BOOL>
+    block_comment_gutters = <BOOL>
+    upper = <BOOL>
+    lower = <BOOL>
+    consistent = <BOOL>
+    identifier_case = <CASE>
+    function_case = <CASE>
+    datatype_case = <CASE>
+    tabs = <BOOL>
+    spaces = <INT>
+    chars = <INT>
+    comment_chars = <INT>
+    paren_chars = <INT>
+    comma_style = <STYLE>
+    layout = <NAME>
+    comment_style = <STYLE>
+    line_endings = <NAME>
+    dialect = <NAME>"
     );
 }
 
@@ -111,17 +311,46 @@ fn print_version() {
     println!("{version}");
 }
 
-fn get_input_sql(input: &Option<String>) -> Result<String, io::Error> {
+fn get_inputs(args: &arguments::Arguments) -> Result<Vec<Option<PathBuf>>, io::Error> {
+    let mut paths: Vec<String> = vec![];
+    if let Some(input) = &args.input {
+        paths.push(input.clone());
+    }
+    paths.extend(args.paths.iter().cloned());
+
+    if paths.is_empty() {
+        return Ok(vec![None]);
+    }
+
+    let sql_files: Vec<PathBuf> = files::get_sql_files(&paths, &args.include, &args.exclude)?;
+    return Ok(sql_files.into_iter().map(Some).collect());
+}
+
+fn get_input_sql(input: &Option<PathBuf>) -> Result<String, io::Error> {
+    if let Some(path) = input {
+        return fs::read_to_string(path);
+    }
+
     let stdin: io::Stdin = io::stdin();
     if stdin.is_terminal() {
-        if input.is_none() {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "Input file not defined.",
-            ));
-        }
-        return fs::read_to_string(input.as_ref().unwrap());
-    } else {
-        return io::read_to_string(stdin);
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Input file not defined.",
+        ));
+    }
+    return io::read_to_string(stdin);
+}
+
+fn get_input_name(input: &Option<PathBuf>) -> String {
+    if let Some(path) = input {
+        return path.display().to_string();
+    }
+    return String::from("<stdin>");
+}
+
+fn write_output_sql(path: &Path, sql: &str) {
+    if let Err(result) = fs::write(path, sql) {
+        print_error(result.to_string().as_str());
+        process::exit(1);
     }
 }
@@ -1,19 +1,24 @@
-use crate::configuration::Configuration;
+#![allow(clippy::needless_return)]
+
+use crate::configuration::{ConfigCommaStyle, ConfigDialect, Configuration};
+use crate::diagnostic::{Diagnostic, DiagnosticKind, get_position_after};
 
 const AMPERSAND: char = '&';
 const ASTERISK: char = '*';
 const BACKTICK: char = '`';
 const BRACKET_CLOSE: char = ']';
 const BRACKET_OPEN: char = '[';
-const CARRIAGE_RETURN: char = '\r';
+pub const CARRIAGE_RETURN: char = '\r';
 const CIRCUMFLEX: char = '^';
 const COMMA: char = ',';
 const CURLY_BRACKET_CLOSE: char = '}';
 const CURLY_BRACKET_OPEN: char = '{';
+const DOLLAR: char = '$';
 const EQUAL: char = '=';
 const EXCLAMATION: char = '!';
 pub const FULL_STOP: char = '.';
 const GREATER_THAN: char = '>';
+const HASH: char = '#';
 const HYPHEN: char = '-';
 const LESS_THAN: char = '<';
 pub const NEW_LINE: char = '\n';
@@ -24,11 +29,23 @@ const PLUS: char = '+';
 const QUOTE_DOUBLE: char = '"';
 const QUOTE_SINGLE: char = '\'';
 const SLASH_FORWARD: char = '/';
+const SPACE: char = ' ';
 pub const TAB: char = '\t';
+const UNDERSCORE: char = '_';
 const VERTICAL_BAR: char = '|';
 
 pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
+    return get_sql_tokens_and_diagnostics(config, input_sql).0;
+}
+
+/// Get SQL tokens, with diagnostics for unterminated quotes and block comments.
+#[allow(clippy::collapsible_if, clippy::if_same_then_else)]
+pub fn get_sql_tokens_and_diagnostics(
+    config: &Configuration,
+    input_sql: String,
+) -> (Vec<Token>, Vec<Diagnostic>) {
     let mut tokens: Vec<Token> = vec![];
+    let mut diagnostics: Vec<Diagnostic> = vec![];
 
     let mut delimiter: String = String::from(";");
 
@@ -43,6 +60,7 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
         let prev1_ch: Option<&char> = if i >= 1 { sql_chars.get(i - 1) } else { None };
         let curr_ch: char = sql_chars[i];
         let next1_ch: Option<&char> = sql_chars.get(i + 1);
+        let next2_ch: Option<&char> = sql_chars.get(i + 2);
 
         match in_special {
             None => {
@@ -79,7 +97,9 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
                     prev1_ch,
                     curr_ch,
                     next1_ch,
+                    next2_ch,
                     curr_token.len(),
+                    &config.dialect,
                 ) {
                     if !curr_token.is_empty() {
                         curr_token.setup(config);
@@ -90,6 +110,8 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
                     curr_token.category = Some(TokenCategory::Comment);
                 } else if let Some(qc) =
                     get_in_quote(&None, prev1_ch, curr_ch, next1_ch, &curr_token)
+                        .or_else(|| get_in_dollar_quote(prev1_ch, &sql_chars[i..], &delimiter))
+                        .filter(|qc| is_dialect_quote(&config.dialect, qc))
                 {
                     if !curr_token.is_empty() {
                         curr_token.setup(config);
@@ -128,24 +150,35 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
                 }
             }
             Some(TokenSpecial::Comment(cc)) => {
-                if get_in_comment(
+                if let Some(cc) = get_in_comment(
                     &Some(cc),
                     prev2_ch,
                     prev1_ch,
                     curr_ch,
                     next1_ch,
+                    next2_ch,
                     curr_token.len(),
-                )
-                .is_some()
-                {
+                    &config.dialect,
+                ) {
+                    in_special = Some(TokenSpecial::Comment(cc));
                     curr_token.value.push(curr_ch);
                     continue;
                 } else {
                     in_special = None;
+                    let directive: Option<VerbatimCategory> = get_directive(&curr_token.value);
+                    curr_token.setup(config);
                     tokens.push(curr_token);
                     curr_token = Token::new();
+
+                    // `off` directive starts a verbatim region at its newline,
+                    // `skip-next` regions are merged once the statement is tokenized
+                    if directive == Some(VerbatimCategory::Off) {
+                        curr_token.category = Some(TokenCategory::Verbatim);
+                        in_special = Some(TokenSpecial::Verbatim);
+                    }
                 }
             }
+            Some(TokenSpecial::Verbatim) => (),
             Some(TokenSpecial::Quote(qc)) => {
                 if get_in_quote(&Some(qc), prev1_ch, curr_ch, next1_ch, &curr_token).is_some() {
                     curr_token.value.push(curr_ch);
@@ -159,6 +192,16 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
             }
         }
 
+        if let Some(TokenSpecial::Verbatim) = in_special {
+            curr_token.value.push(curr_ch);
+            if !get_in_verbatim(&sql_chars[i..]) {
+                in_special = None;
+                tokens.push(curr_token);
+                curr_token = Token::new();
+            }
+            continue;
+        }
+
         match curr_ch {
             // always single character tokens
             NEW_LINE | COMMA | FULL_STOP | PAREN_OPEN | PAREN_CLOSE | AMPERSAND | VERTICAL_BAR
@@ -259,15 +302,79 @@ pub fn get_sql_tokens(config: &Configuration, input_sql: String) -> Vec<Token> {
         }
     }
 
+    // check if input ended inside a quote or block comment
+    let last_ch: Option<&char> = sql_chars.last();
+    let kind: Option<DiagnosticKind> = match in_special {
+        Some(TokenSpecial::Quote(qc)) => get_in_quote(&Some(qc), last_ch, SPACE, None, &curr_token)
+            .map(|_| DiagnosticKind::UnterminatedQuote),
+        Some(TokenSpecial::Comment(cc)) => get_in_comment(
+            &Some(cc),
+            sql_chars
+                .len()
+                .checked_sub(2)
+                .and_then(|i| sql_chars.get(i)),
+            last_ch,
+            SPACE,
+            None,
+            None,
+            curr_token.len(),
+            &config.dialect,
+        )
+        .filter(|cc| matches!(cc, CommentCategory::MultiLine(_)))
+        .map(|_| DiagnosticKind::UnclosedComment),
+        _ => None,
+    };
+
     if !curr_token.is_empty() {
         curr_token.setup(config);
         tokens.push(curr_token);
     }
 
-    return tokens;
+    let mut tokens: Vec<Token> = get_skip_next_merged(tokens);
+    set_token_positions(&mut tokens, &input_sql);
+
+    if let Some(kind) = kind
+        && let Some(position) = tokens.last().and_then(|t| t.position)
+    {
+        diagnostics.push(Diagnostic::new(
+            kind,
+            (position.line, position.column),
+            (position.end_line, position.end_column),
+        ));
+    }
+
+    return (tokens, diagnostics);
 }
 
-fn get_last_nonspace_token(tokens: &Vec<Token>) -> Option<&Token> {
+/// Set the position of each token in the input SQL, which still has the carriage returns removed from tokens.
+///
+/// Carriage returns are part of the token after them, so newline tokens cover `\r\n`.
+fn set_token_positions(tokens: &mut [Token], input_sql: &str) {
+    let mut offset: usize = 0;
+    let mut line_column: (usize, usize) = (1, 1);
+    for token in tokens {
+        let start: usize = offset;
+        for c in token.value.chars() {
+            while input_sql[offset..].starts_with(CARRIAGE_RETURN) {
+                offset += 1;
+            }
+            offset += c.len_utf8();
+        }
+
+        let end_line_column: (usize, usize) = get_position_after(line_column, &token.value);
+        token.position = Some(TokenPosition {
+            start,
+            end: offset,
+            line: line_column.0,
+            column: line_column.1,
+            end_line: end_line_column.0,
+            end_column: end_line_column.1,
+        });
+        line_column = end_line_column;
+    }
+}
+
+fn get_last_nonspace_token(tokens: &[Token]) -> Option<&Token> {
     for i in (0..tokens.len()).rev() {
         let token: &Token = &tokens[i];
         if token.category == Some(TokenCategory::WhiteSpace) {
@@ -287,7 +394,9 @@ fn get_in_delimiter_change(
         return false;
     }
 
-    if prev_token.is_some_and(|t| t.value.to_uppercase() == "DELIMITER") {
+    if prev_token.is_some_and(|t| {
+        t.category == Some(TokenCategory::Keyword) && t.value.to_uppercase() == "DELIMITER"
+    }) {
         return true;
     }
 
@@ -332,18 +441,21 @@ fn get_in_interpolation(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn get_in_comment(
     in_comment: &Option<CommentCategory>,
     prev2_ch: Option<&char>,
     prev1_ch: Option<&char>,
     curr_ch: char,
     next1_ch: Option<&char>,
+    next2_ch: Option<&char>,
     curr_token_len: usize,
+    dialect: &ConfigDialect,
 ) -> Option<CommentCategory> {
     match in_comment {
         Some(cc) => {
             if curr_token_len <= 1 {
-                return in_comment.clone();
+                return *in_comment;
             }
 
             match cc {
@@ -353,24 +465,46 @@ fn get_in_comment(
                     }
                     return Some(CommentCategory::SingleLine);
                 }
-                CommentCategory::MultiLine => {
+                CommentCategory::MultiLine(depth) => {
+                    let depth: usize = *depth;
+                    let is_closed: bool =
+                        prev2_ch == Some(&ASTERISK) && prev1_ch == Some(&SLASH_FORWARD);
                     if curr_ch == SLASH_FORWARD && next1_ch == Some(&ASTERISK) {
-                        return Some(CommentCategory::MultiLine);
+                        // comment right after a closed one continues at the same depth
+                        if !is_closed
+                            && prev1_ch != Some(&ASTERISK)
+                            && is_dialect_nested_comment(dialect)
+                        {
+                            return Some(CommentCategory::MultiLine(depth + 1));
+                        }
+                        return Some(CommentCategory::MultiLine(depth));
                     }
-                    if prev2_ch == Some(&ASTERISK) && prev1_ch == Some(&SLASH_FORWARD) {
-                        return None;
+                    if is_closed {
+                        if depth <= 1 {
+                            return None;
+                        }
+                        return Some(CommentCategory::MultiLine(depth - 1));
                     }
-                    return Some(CommentCategory::MultiLine);
+                    return Some(CommentCategory::MultiLine(depth));
                 }
             }
         }
         None => {
             if curr_ch == HYPHEN && next1_ch == Some(&HYPHEN) {
+                // mysql requires whitespace after double dash
+                if dialect == &ConfigDialect::Mysql && next2_ch.is_some_and(|c| !c.is_whitespace())
+                {
+                    return None;
+                }
+                return Some(CommentCategory::SingleLine);
+            }
+
+            if curr_ch == HASH && is_dialect_hash_comment(dialect) {
                 return Some(CommentCategory::SingleLine);
             }
 
             if curr_ch == SLASH_FORWARD && next1_ch == Some(&ASTERISK) {
-                return Some(CommentCategory::MultiLine);
+                return Some(CommentCategory::MultiLine(1));
             }
 
             return None;
@@ -378,6 +512,7 @@ fn get_in_comment(
     }
 }
 
+#[allow(clippy::collapsible_if)]
 fn get_in_quote(
     in_quote: &Option<QuoteCategory>,
     prev1_ch: Option<&char>,
@@ -388,7 +523,7 @@ fn get_in_quote(
     match in_quote {
         Some(qc) => {
             if curr_token.len() <= 1 {
-                return in_quote.clone();
+                return *in_quote;
             }
 
             match qc {
@@ -396,27 +531,38 @@ fn get_in_quote(
                     if prev1_ch == Some(&BACKTICK) {
                         return None;
                     }
-                    return in_quote.clone();
+                    return *in_quote;
                 }
                 QuoteCategory::QuoteSingle => {
                     if prev1_ch == Some(&QUOTE_SINGLE) && curr_ch != QUOTE_SINGLE {
-                        if curr_token.count(QUOTE_SINGLE) % 2 == 0 {
+                        if curr_token.count(QUOTE_SINGLE).is_multiple_of(2) {
                             return None;
                         }
                     }
-                    return in_quote.clone();
+                    return *in_quote;
                 }
                 QuoteCategory::QuoteDouble => {
                     if prev1_ch == Some(&QUOTE_DOUBLE) {
                         return None;
                     }
-                    return in_quote.clone();
+                    return *in_quote;
                 }
                 QuoteCategory::Bracket => {
                     if prev1_ch == Some(&BRACKET_CLOSE) {
                         return None;
                     }
-                    return in_quote.clone();
+                    return *in_quote;
+                }
+                QuoteCategory::Dollar => {
+                    // closing tag must match opening tag
+                    if let Some(tag_len) = curr_token.value[1..].find(DOLLAR).map(|p| p + 2) {
+                        let tag: &str = &curr_token.value[..tag_len];
+                        if curr_token.value.len() >= tag_len * 2 && curr_token.value.ends_with(tag)
+                        {
+                            return None;
+                        }
+                    }
+                    return *in_quote;
                 }
             }
         }
@@ -438,11 +584,184 @@ fn get_in_quote(
     }
 }
 
+fn get_in_dollar_quote(
+    prev1_ch: Option<&char>,
+    chars: &[char],
+    delimiter: &str,
+) -> Option<QuoteCategory> {
+    if chars.first() != Some(&DOLLAR) {
+        return None;
+    }
+
+    // part of identifier (ex. V$SESSION)
+    if prev1_ch.is_some_and(|c| c.is_alphanumeric() || c == &UNDERSCORE || c == &DOLLAR) {
+        return None;
+    }
+
+    // changed delimiter (ex. DELIMITER $$)
+    if delimiter.starts_with(DOLLAR)
+        && chars
+            .iter()
+            .take(delimiter.chars().count())
+            .collect::<String>()
+            == delimiter
+    {
+        return None;
+    }
+
+    // tag follows identifier rules, but cannot start with digit (ex. $1 parameter)
+    for (i, c) in chars.iter().enumerate().skip(1) {
+        if c == &DOLLAR {
+            return Some(QuoteCategory::Dollar);
+        }
+        if !(c.is_alphabetic() || c == &UNDERSCORE || (i > 1 && c.is_ascii_digit())) {
+            return None;
+        }
+    }
+
+    return None;
+}
+
+/// Get the verbatim region started by a `-- sqlfmt: off` or `-- sqlfmt: skip-next` comment.
+fn get_directive(comment: &str) -> Option<VerbatimCategory> {
+    return match get_directive_value(comment)? {
+        "off" => Some(VerbatimCategory::Off),
+        "skip-next" => Some(VerbatimCategory::SkipNext),
+        _ => None,
+    };
+}
+
+/// Get the value of a `-- sqlfmt: <value>` directive comment.
+pub fn get_directive_value(comment: &str) -> Option<&str> {
+    let comment: &str = comment.trim();
+    let value: &str = comment
+        .strip_prefix("--")
+        .or_else(|| comment.strip_prefix(HASH))?
+        .trim_start();
+    return Some(value.strip_prefix("sqlfmt:")?.trim());
+}
+
+/// Check if still in a `-- sqlfmt: off` region after the current character.
+///
+/// Regions end with a newline before the `-- sqlfmt: on` line.
+fn get_in_verbatim(chars: &[char]) -> bool {
+    if chars[0] == NEW_LINE {
+        let next_line: String = chars[1..].iter().take_while(|c| c != &&NEW_LINE).collect();
+        if get_directive_value(&next_line) == Some("on") {
+            return false;
+        }
+    }
+    return true;
+}
+
+/// Merge the tokens after each `-- sqlfmt: skip-next` comment into a verbatim token.
+///
+/// The region ends after the line of the next delimiter token, so delimiters
+/// inside quotes, comments and dollar-quoted bodies do not end it.
+fn get_skip_next_merged(tokens: Vec<Token>) -> Vec<Token> {
+    let mut merged: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut verbatim: Option<(Token, bool)> = None;
+    for token in tokens {
+        if let Some((mut verbatim_token, mut ended)) = verbatim.take() {
+            verbatim_token.value.push_str(&token.value);
+            ended = ended || token.category == Some(TokenCategory::Delimiter);
+            if ended && token.category == Some(TokenCategory::NewLine) {
+                merged.push(verbatim_token);
+            } else {
+                verbatim = Some((verbatim_token, ended));
+            }
+            continue;
+        }
+
+        if token.category == Some(TokenCategory::Comment)
+            && get_directive(&token.value) == Some(VerbatimCategory::SkipNext)
+        {
+            let mut verbatim_token: Token = Token::new();
+            verbatim_token.category = Some(TokenCategory::Verbatim);
+            verbatim = Some((verbatim_token, false));
+        }
+        merged.push(token);
+    }
+
+    if let Some((verbatim_token, _)) = verbatim
+        && !verbatim_token.is_empty()
+    {
+        merged.push(verbatim_token);
+    }
+
+    return merged;
+}
+
+fn is_dialect_quote(dialect: &ConfigDialect, quote: &QuoteCategory) -> bool {
+    let dialects: &[ConfigDialect] = match quote {
+        QuoteCategory::QuoteSingle | QuoteCategory::QuoteDouble => return true,
+        QuoteCategory::Backtick => &[
+            ConfigDialect::Mysql,
+            ConfigDialect::Sqlite,
+            ConfigDialect::Bigquery,
+        ],
+        QuoteCategory::Bracket => &[ConfigDialect::Tsql, ConfigDialect::Sqlite],
+        QuoteCategory::Dollar => &[ConfigDialect::Postgres, ConfigDialect::Snowflake],
+    };
+    return dialect == &ConfigDialect::Generic || dialects.contains(dialect);
+}
+
+/// Check if dialect starts line comments with `#`.
+fn is_dialect_hash_comment(dialect: &ConfigDialect) -> bool {
+    return matches!(dialect, ConfigDialect::Mysql | ConfigDialect::Bigquery);
+}
+
+/// Check if dialect allows block comments nested in block comments.
+pub fn is_dialect_nested_comment(dialect: &ConfigDialect) -> bool {
+    return matches!(dialect, ConfigDialect::Postgres | ConfigDialect::Tsql);
+}
+
+fn is_dialect_value(dialect: &ConfigDialect, value: &str) -> bool {
+    let dialects: &[ConfigDialect] = match value {
+        "CATCH" | "EXEC" | "GO" | "TRAN" | "TRY" => &[ConfigDialect::Tsql],
+        "DELIMITER" => &[ConfigDialect::Mysql],
+        "ILIKE" => &[ConfigDialect::Postgres, ConfigDialect::Snowflake],
+        "LIMIT" => &[
+            ConfigDialect::Postgres,
+            ConfigDialect::Mysql,
+            ConfigDialect::Sqlite,
+            ConfigDialect::Bigquery,
+            ConfigDialect::Snowflake,
+        ],
+        "QUALIFY" => &[ConfigDialect::Bigquery, ConfigDialect::Snowflake],
+        "RETURNING" => &[ConfigDialect::Postgres, ConfigDialect::Sqlite],
+        "TOP" => &[ConfigDialect::Tsql, ConfigDialect::Snowflake],
+        _ => return true,
+    };
+    return dialect == &ConfigDialect::Generic || dialects.contains(dialect);
+}
+
+/// Check if value is a keyword or function name in any dialect.
+pub fn is_known_value(value: &str) -> bool {
+    return get_category_from_value(&value.to_uppercase()).is_some();
+}
+
+/// Position of a token in the input SQL.
+///
+/// Offsets are bytes, end exclusive. Lines and columns start at 1 and count characters,
+/// ignoring carriage returns, with the end being the position after the token.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TokenPosition {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
 #[derive(Clone)]
 pub struct Token {
     pub value: String,
     pub category: Option<TokenCategory>,
     pub behavior: Vec<TokenBehavior>,
+    /// Position in the input, none for tokens added when formatting.
+    pub position: Option<TokenPosition>,
 }
 
 impl std::fmt::Debug for Token {
@@ -466,6 +785,7 @@ impl Token {
             value: String::new(),
             category: None,
             behavior: vec![],
+            position: None,
         }
     }
 
@@ -473,8 +793,9 @@ impl Token {
     fn new_test(value: &str, category: Option<TokenCategory>) -> Token {
         Token {
             value: String::from(value),
-            category: category,
+            category,
             behavior: vec![],
+            position: None,
         }
     }
 
@@ -483,6 +804,7 @@ impl Token {
             value,
             category: Some(TokenCategory::WhiteSpace),
             behavior: vec![TokenBehavior::NoSpaceBefore, TokenBehavior::NoSpaceAfter],
+            position: None,
         }
     }
 
@@ -494,6 +816,7 @@ impl Token {
                 TokenBehavior::NoWhiteSpaceBefore,
                 TokenBehavior::NoNewLineAfterX2Skip,
             ],
+            position: None,
         }
     }
 
@@ -504,7 +827,7 @@ impl Token {
         return self.value.len();
     }
 
-    fn is_empty(&self) -> bool {
+    pub fn is_empty(&self) -> bool {
         self.value.is_empty()
     }
 
@@ -513,11 +836,12 @@ impl Token {
     }
 
     fn setup(&mut self, config: &Configuration) {
-        self.category = self.get_category();
+        self.category = self.get_category(config);
         self.set_behavior(config);
     }
 
-    fn get_category(&self) -> Option<TokenCategory> {
+    #[allow(clippy::collapsible_if)]
+    fn get_category(&self, config: &Configuration) -> Option<TokenCategory> {
         if self.category == Some(TokenCategory::Quote) {
             let mut quote_chars = self.value.chars();
             if let Some(open_quote) = quote_chars.next()
@@ -572,7 +896,11 @@ impl Token {
             return Some(TokenCategory::WhiteSpace);
         }
 
-        return get_category_from_value(self.value.to_uppercase().as_str());
+        let value: String = self.value.to_uppercase();
+        if !is_dialect_value(&config.dialect, &value) {
+            return None;
+        }
+        return get_category_from_value(&value);
     }
 
     fn set_behavior(&mut self, config: &Configuration) {
@@ -595,7 +923,10 @@ impl Token {
             }
             Some(TokenCategory::Comma) => {
                 behavior.push(TokenBehavior::NoSpaceBefore);
-                behavior.push(TokenBehavior::NewLineAfter);
+                match config.comma_style {
+                    ConfigCommaStyle::Leading => behavior.push(TokenBehavior::NewLineBefore),
+                    ConfigCommaStyle::Trailing => behavior.push(TokenBehavior::NewLineAfter),
+                }
             }
             Some(TokenCategory::FullStop) => {
                 behavior.push(TokenBehavior::NoWhiteSpaceBefore);
@@ -605,7 +936,12 @@ impl Token {
                 behavior.push(TokenBehavior::NoSpaceAroundIfNotProvidedInput);
             }
             Some(TokenCategory::Comment) => {
-                if config.comment_pre_space {
+                // keep optimizer hints on the line of their keyword
+                if self.value.starts_with("/*+") {
+                    behavior.push(TokenBehavior::NoNewLineBefore);
+                    behavior.push(TokenBehavior::NoNewLineAfterX2Skip);
+                    behavior.push(TokenBehavior::NewLineAfter);
+                } else if config.comment_pre_space {
                     behavior.push(TokenBehavior::NewLineBefore);
                     behavior.push(TokenBehavior::NoNewLineAfterX2Skip);
                     behavior.push(TokenBehavior::NewLineAfter);
@@ -620,7 +956,13 @@ impl Token {
             _ => (),
         }
 
-        match self.value.to_uppercase().as_str() {
+        let value: String = self.value.to_uppercase();
+        if !is_dialect_value(&config.dialect, &value) {
+            self.behavior = behavior;
+            return;
+        }
+
+        match value.as_str() {
             "AFTER" => {
                 behavior.push(TokenBehavior::NewLineBefore);
             }
@@ -1972,6 +2314,7 @@ pub enum TokenCategory {
     Interpolation,
     Comment,
     Quote,
+    Verbatim,
     Comma,
     FullStop,
     ParenOpen,
@@ -2017,6 +2360,7 @@ enum TokenSpecial {
     Interpolation(InterpolationCategory),
     Comment(CommentCategory),
     Quote(QuoteCategory),
+    Verbatim,
 }
 
 #[derive(Clone, Copy)]
@@ -2028,7 +2372,14 @@ enum InterpolationCategory {
 #[derive(Clone, Copy)]
 enum CommentCategory {
     SingleLine,
-    MultiLine,
+    /// Block comment, with its nesting depth.
+    MultiLine(usize),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VerbatimCategory {
+    Off,
+    SkipNext,
 }
 
 #[derive(Clone, Copy)]
@@ -2037,9 +2388,11 @@ enum QuoteCategory {
     QuoteSingle,
     QuoteDouble,
     Bracket,
+    Dollar,
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -2601,6 +2954,490 @@ Name'"#,
         );
     }
 
+    #[test]
+    fn test_get_sql_tokens_and_diagnostics_quote() {
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(
+                &Configuration::new(),
+                String::from("SELECT\n  'Column")
+            )
+            .1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnterminatedQuote,
+                (2, 3),
+                (2, 10)
+            )]
+        );
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(&Configuration::new(), String::from("SELECT '"),).1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnterminatedQuote,
+                (1, 8),
+                (1, 9)
+            )]
+        );
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(&Configuration::new(), String::from("SELECT 'a''")).1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnterminatedQuote,
+                (1, 8),
+                (1, 12)
+            )]
+        );
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(
+                &Configuration::new(),
+                String::from("SELECT 'a', \"b\", [c], `d`, $$e$$")
+            )
+            .1,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_position() {
+        let tokens: Vec<Token> =
+            get_sql_tokens(&Configuration::new(), String::from("SELECT 'é'\r\n  , b"));
+        assert_eq!(
+            tokens.iter().map(|t| t.position).collect::<Vec<_>>(),
+            vec![
+                Some(TokenPosition {
+                    start: 0,
+                    end: 6,
+                    line: 1,
+                    column: 1,
+                    end_line: 1,
+                    end_column: 7,
+                }),
+                Some(TokenPosition {
+                    start: 6,
+                    end: 7,
+                    line: 1,
+                    column: 7,
+                    end_line: 1,
+                    end_column: 8,
+                }),
+                Some(TokenPosition {
+                    start: 7,
+                    end: 11,
+                    line: 1,
+                    column: 8,
+                    end_line: 1,
+                    end_column: 11,
+                }),
+                Some(TokenPosition {
+                    start: 11,
+                    end: 13,
+                    line: 1,
+                    column: 11,
+                    end_line: 2,
+                    end_column: 1,
+                }),
+                Some(TokenPosition {
+                    start: 13,
+                    end: 15,
+                    line: 2,
+                    column: 1,
+                    end_line: 2,
+                    end_column: 3,
+                }),
+                Some(TokenPosition {
+                    start: 15,
+                    end: 16,
+                    line: 2,
+                    column: 3,
+                    end_line: 2,
+                    end_column: 4,
+                }),
+                Some(TokenPosition {
+                    start: 16,
+                    end: 17,
+                    line: 2,
+                    column: 4,
+                    end_line: 2,
+                    end_column: 5,
+                }),
+                Some(TokenPosition {
+                    start: 17,
+                    end: 18,
+                    line: 2,
+                    column: 5,
+                    end_line: 2,
+                    end_column: 6,
+                }),
+            ]
+        );
+        assert_eq!(Token::new_newline().position, None);
+    }
+
+    #[test]
+    fn test_get_sql_tokens_and_diagnostics_comment() {
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(
+                &Configuration::new(),
+                String::from("SELECT 1 /* a\r\n b")
+            )
+            .1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnclosedComment,
+                (1, 10),
+                (2, 3)
+            )]
+        );
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(
+                &Configuration::new(),
+                String::from("SELECT 1 /* a */ -- b")
+            )
+            .1,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_directive_off() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: off\nSELECT  1;\n  -- sqlfmt: on\nSELECT")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: off", Some(TokenCategory::Comment)),
+                Token::new_test("\nSELECT  1;\n", Some(TokenCategory::Verbatim)),
+                Token::new_test("  ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("-- sqlfmt: on", Some(TokenCategory::Comment)),
+                Token::new_test("\n", Some(TokenCategory::NewLine)),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("--sqlfmt:off\n-- sqlfmt: on")
+            ),
+            vec![
+                Token::new_test("--sqlfmt:off", Some(TokenCategory::Comment)),
+                Token::new_test("\n", Some(TokenCategory::Verbatim)),
+                Token::new_test("-- sqlfmt: on", Some(TokenCategory::Comment)),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: off\nSELECT  1")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: off", Some(TokenCategory::Comment)),
+                Token::new_test("\nSELECT  1", Some(TokenCategory::Verbatim)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_directive_skip_next() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: skip-next\nSELECT\n  ';'  ; -- x\nSELECT")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: skip-next", Some(TokenCategory::Comment)),
+                Token::new_test("\nSELECT\n  ';'  ; -- x\n", Some(TokenCategory::Verbatim)),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: skip-next\nSELECT a, -- note; here\n  b FROM t;\nSELECT")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: skip-next", Some(TokenCategory::Comment)),
+                Token::new_test(
+                    "\nSELECT a, -- note; here\n  b FROM t;\n",
+                    Some(TokenCategory::Verbatim)
+                ),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: skip-next\nSELECT 'a;b''c;'  ;\nSELECT")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: skip-next", Some(TokenCategory::Comment)),
+                Token::new_test("\nSELECT 'a;b''c;'  ;\n", Some(TokenCategory::Verbatim)),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_directive_skip_next_dollar_quote() {
+        let mut config: Configuration = Configuration::new();
+        config.dialect = ConfigDialect::Postgres;
+        let sql: &str = "-- sqlfmt: skip-next\nCREATE FUNCTION f() RETURNS int AS $$\nBEGIN\n  RETURN 1;\nEND;\n$$ LANGUAGE plpgsql;\nSELECT";
+        let (tokens, diagnostics): (Vec<Token>, Vec<Diagnostic>) =
+            get_sql_tokens_and_diagnostics(&config, String::from(sql));
+        assert_eq!(
+            tokens,
+            vec![
+                Token::new_test("-- sqlfmt: skip-next", Some(TokenCategory::Comment)),
+                Token::new_test(
+                    "\nCREATE FUNCTION f() RETURNS int AS $$\nBEGIN\n  RETURN 1;\nEND;\n$$ LANGUAGE plpgsql;\n",
+                    Some(TokenCategory::Verbatim)
+                ),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+        assert_eq!(diagnostics, vec![]);
+    }
+
+    #[test]
+    fn test_get_sql_tokens_directive_not() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("-- sqlfmt: unknown\n/* sqlfmt: off */\nSELECT")
+            ),
+            vec![
+                Token::new_test("-- sqlfmt: unknown", Some(TokenCategory::Comment)),
+                Token::new_test("\n", Some(TokenCategory::NewLine)),
+                Token::new_test("/* sqlfmt: off */", Some(TokenCategory::Comment)),
+                Token::new_test("\n", Some(TokenCategory::NewLine)),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_quote_dollar() {
+        assert_eq!(
+            get_sql_tokens(&Configuration::new(), String::from("SELECT $$it's; (x)$$")),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$$it's; (x)$$", Some(TokenCategory::Quote)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_quote_dollar_tag() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from(
+                    r#"AS $body$
+BEGIN
+    RETURN $$a$$ || $1;
+END;
+$body$ LANGUAGE plpgsql;"#
+                )
+            ),
+            vec![
+                Token::new_test("AS", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test(
+                    r#"$body$
+BEGIN
+    RETURN $$a$$ || $1;
+END;
+$body$"#,
+                    Some(TokenCategory::Quote)
+                ),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("LANGUAGE", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("plpgsql", None),
+                Token::new_test(";", Some(TokenCategory::Delimiter)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_quote_dollar_abrupt_end() {
+        assert_eq!(
+            get_sql_tokens(&Configuration::new(), String::from("SELECT $a$ Column $a")),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$a$ Column $a", Some(TokenCategory::Quote)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_dollar_not_quote() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("SELECT $1, V$SESSION, $1$")
+            ),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$1", None),
+                Token::new_test(",", Some(TokenCategory::Comma)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("V$SESSION", None),
+                Token::new_test(",", Some(TokenCategory::Comma)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$1$", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_dollar_delimiter() {
+        assert_eq!(
+            get_sql_tokens(
+                &Configuration::new(),
+                String::from("DELIMITER $$ SELECT 1 $$ DELIMITER ;")
+            ),
+            vec![
+                Token::new_test("DELIMITER", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$$", Some(TokenCategory::Delimiter)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("1", None),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$$", Some(TokenCategory::Delimiter)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("DELIMITER", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test(";", Some(TokenCategory::Delimiter)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_dialect_quote() {
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Postgres)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("arr[1]")),
+            vec![Token::new_test("arr[1]", None)]
+        );
+
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Tsql)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("[a b]")),
+            vec![Token::new_test("[a b]", Some(TokenCategory::Quote))]
+        );
+        assert_eq!(
+            get_sql_tokens(&config, String::from("$$a$$"))
+                .iter()
+                .any(|t| t.category == Some(TokenCategory::Quote)),
+            false
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_dialect_comment() {
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Mysql)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("SELECT 1--1")),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("1", None),
+                Token::new_test("-", Some(TokenCategory::Operator)),
+                Token::new_test("-1", None),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(&config, String::from("SELECT 1 -- a")),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("1", None),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("-- a", Some(TokenCategory::Comment)),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(&config, String::from("SELECT a# b\nFROM t")),
+            vec![
+                Token::new_test("SELECT", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("a", None),
+                Token::new_test("# b", Some(TokenCategory::Comment)),
+                Token::new_test("\n", Some(TokenCategory::NewLine)),
+                Token::new_test("FROM", Some(TokenCategory::Keyword)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("t", None),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens(&Configuration::new(), String::from("#temp")),
+            vec![Token::new_test("#temp", None)]
+        );
+
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Postgres)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("/* a /* b */ c */ 1")),
+            vec![
+                Token::new_test("/* a /* b */ c */", Some(TokenCategory::Comment)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("1", None),
+            ]
+        );
+        assert_eq!(
+            get_sql_tokens_and_diagnostics(&config, String::from("/* a /* b */ c")).1,
+            vec![Diagnostic::new(
+                DiagnosticKind::UnclosedComment,
+                (1, 1),
+                (1, 15)
+            )]
+        );
+        assert_eq!(
+            get_sql_tokens(&Configuration::new(), String::from("/* a /* b */ c")),
+            vec![
+                Token::new_test("/* a /* b */", Some(TokenCategory::Comment)),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("c", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_sql_tokens_dialect_keyword() {
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Postgres)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("GO")),
+            vec![Token::new_test("GO", None)]
+        );
+        assert_eq!(
+            get_sql_tokens(&Configuration::new(), String::from("GO")),
+            vec![Token::new_test("GO", Some(TokenCategory::Keyword))]
+        );
+
+        let config: Configuration = Configuration::builder()
+            .dialect(ConfigDialect::Tsql)
+            .build();
+        assert_eq!(
+            get_sql_tokens(&config, String::from("DELIMITER $$")),
+            vec![
+                Token::new_test("DELIMITER", None),
+                Token::new_test(" ", Some(TokenCategory::WhiteSpace)),
+                Token::new_test("$$", None),
+            ]
+        );
+    }
+
     #[test]
     fn test_get_sql_tokens_delimiter_basic() {
         assert_eq!(
@@ -1,5 +1,6 @@
 import * as vscode from "vscode";
 import cp from "child_process";
+import * as path from "path";
 import { getBinaryPath } from "./binary";
 
 export function activate(context: vscode.ExtensionContext) {
@@ -35,7 +36,7 @@ function getSqlfmtCommand(binaryPath: string | null): vscode.Disposable {
       return;
     }
 
-    getFormattedSql(binaryPath, editor.document.getText(range))
+    getFormattedSql(binaryPath, editor.document, editor.document.getText(range))
       .then((formattedSql: string) => {
         editor.edit((tee: vscode.TextEditorEdit) => tee.replace(range, formattedSql));
         vscode.window.showInformationMessage("SQL is formatted.");
@@ -53,7 +54,7 @@ function setupLanguageFormatting(binaryPath: string) {
       const range = new vscode.Range(firstLine.range.start, lastLine.range.end);
 
       try {
-        const formattedSql: string = await getFormattedSql(binaryPath, document.getText(range));
+        const formattedSql: string = await getFormattedSql(binaryPath, document, document.getText(range));
         return [vscode.TextEdit.replace(range, formattedSql)];
       }
       catch {
@@ -66,7 +67,7 @@ function setupLanguageFormatting(binaryPath: string) {
   vscode.languages.registerDocumentRangeFormattingEditProvider("sql", {
     async provideDocumentRangeFormattingEdits(document: vscode.TextDocument, range: vscode.Range): Promise<vscode.TextEdit[]> {
       try {
-        const formattedSql: string = await getFormattedSql(binaryPath, document.getText(range));
+        const formattedSql: string = await getFormattedSql(binaryPath, document, document.getText(range));
         return [vscode.TextEdit.replace(range, formattedSql)];
       }
       catch {
@@ -96,11 +97,13 @@ function getRangeToFormat(): vscode.Range | null {
   }
 }
 
-function getFormattedSql(binaryPath: string, inputSql: string): Promise<string> {
+function getFormattedSql(binaryPath: string, document: vscode.TextDocument, inputSql: string): Promise<string> {
   return new Promise((resolve, reject) => {
     try {
       const processArguments: string[] = getProcessArguments();
-      const process = cp.spawn(binaryPath, processArguments);
+      // config file is looked up from the working directory for standard input
+      const cwd: string | undefined = document.uri.scheme === "file" ? path.dirname(document.uri.fsPath) : undefined;
+      const process = cp.spawn(binaryPath, processArguments, { cwd });
 
       process.stdin.write(inputSql);
       process.stdin.end();
@@ -163,6 +166,10 @@ function getProcessArguments(): string[] {
       args.push("-l");
       break;
 
+    case "consistent":
+      args.push("--consistent");
+      break;
+
     default:
       break;
   }